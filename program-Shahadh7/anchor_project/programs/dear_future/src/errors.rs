@@ -11,13 +11,13 @@ pub enum ErrorCode {
     #[msg("Cannot update capsule after it has been unlocked")]
     CapsuleAlreadyUnlocked,
 
-    #[msg("Capsule title is too long (max 100 characters)")]
+    #[msg("Capsule title is longer than the configured limit")]
     TitleTooLong,
 
-    #[msg("Capsule content is too long (max 300 characters)")]
+    #[msg("Capsule content is longer than the configured limit")]
     ContentTooLong,
 
-    #[msg("Encrypted URL is too long (max 500 characters)")]
+    #[msg("Encrypted URL is longer than the configured limit")]
     UrlTooLong,
 
     #[msg("New unlock date must be later than current unlock date (extend only)")]
@@ -40,4 +40,209 @@ pub enum ErrorCode {
 
     #[msg("Cannot transfer capsule to the same owner")]
     CannotTransferToSelf,
+
+    #[msg("Only the capsule owner, a co-owner or the recipient can perform this action")]
+    NotOwnerOrRecipient,
+
+    #[msg("Only the config authority can perform this action")]
+    UnauthorizedAuthority,
+
+    #[msg("The program is currently paused")]
+    ProgramPaused,
+
+    #[msg("Capsule content is locked by its integrity hash")]
+    ContentIsLocked,
+
+    #[msg("Insufficient balance to pay the fee")]
+    InsufficientFeeBalance,
+
+    #[msg("Treasury account does not match the config treasury")]
+    InvalidTreasury,
+
+    #[msg("The unlock date is too far in the future (max ~100 years)")]
+    UnlockDateTooFar,
+
+    #[msg("Config still tracks capsules; pass force to close it anyway")]
+    ConfigHasCapsules,
+
+    #[msg("New authority must differ from the current one and not be the default key")]
+    InvalidNewAuthority,
+
+    #[msg("Archived capsules cannot be updated")]
+    ArchivedCapsuleImmutable,
+
+    #[msg("Capsule is already archived")]
+    CapsuleAlreadyArchived,
+
+    #[msg("Capsule is not archived")]
+    CapsuleNotArchived,
+
+    #[msg("Capsule is not public or not yet unlocked")]
+    CapsuleNotPublic,
+
+    #[msg("Capsule already has the maximum number of co-owners")]
+    TooManyCoOwners,
+
+    #[msg("Key is already an owner or co-owner of this capsule")]
+    CoOwnerAlreadyExists,
+
+    #[msg("Key is not a co-owner of this capsule")]
+    CoOwnerNotFound,
+
+    #[msg("Batch must contain between 1 and the maximum number of capsules")]
+    InvalidBatchSize,
+
+    #[msg("Remaining accounts do not match the expected capsule PDAs")]
+    InvalidBatchAccounts,
+
+    #[msg("Too many capsules created in the current window")]
+    CreationRateLimited,
+
+    #[msg("Rate limit window must be positive")]
+    InvalidRateLimitWindow,
+
+    #[msg("Locked capsules without a mint cannot be transferred")]
+    CannotTransferLockedCapsule,
+
+    #[msg("Only the capsule owner or its delegate can perform this action")]
+    NotOwnerOrDelegate,
+
+    #[msg("Delegates may only update the title, content and URLs")]
+    DelegateNotPermitted,
+
+    #[msg("Relock date must be after the unlock date")]
+    InvalidRelockDate,
+
+    #[msg("Capsule read window has closed")]
+    CapsuleRelocked,
+
+    #[msg("Account is not a capsule owned by this program")]
+    InvalidCapsuleAccount,
+
+    #[msg("Account is already at the current schema version")]
+    AlreadyMigrated,
+
+    #[msg("Discarding a capsule must be explicitly confirmed")]
+    DiscardNotConfirmed,
+
+    #[msg("Content CID exceeds maximum length")]
+    ContentCidTooLong,
+
+    #[msg("Either content or a content CID must be provided")]
+    NoContentProvided,
+
+    #[msg("Encrypted URL must use https://, ipfs:// or ar:// and contain no control characters")]
+    InvalidUrlFormat,
+
+    #[msg("Title and content cannot contain control characters")]
+    InvalidCharacters,
+
+    #[msg("Access passphrase does not match")]
+    InvalidAccessPassphrase,
+
+    #[msg("Content is shorter than the minimum length")]
+    ContentTooShort,
+
+    #[msg("Capsule counter overflow")]
+    CounterOverflow,
+
+    #[msg("Capsule readiness has already been announced")]
+    ReadyAlreadyAnnounced,
+
+    #[msg("Capsule has no pending transfer")]
+    NoPendingTransfer,
+
+    #[msg("Only the pending owner can accept this transfer")]
+    NotPendingOwner,
+
+    #[msg("Length limits must be non-zero and within the hard maximums")]
+    InvalidLimit,
+
+    #[msg("A sealed capsule cannot store content, a content CID or URLs before it is revealed")]
+    SealedContentProvided,
+
+    #[msg("Capsule must be unlocked before its content can be revealed")]
+    CannotRevealLocked,
+
+    #[msg("Revealed content does not match the sealed content hash")]
+    ContentHashMismatch,
+
+    #[msg("Capsule is not sealed")]
+    CapsuleNotSealed,
+
+    #[msg("Only the capsule creator can perform this action")]
+    NotCreator,
+
+    #[msg("Capsule has not been transferred away from its creator")]
+    CapsuleNotTransferred,
+
+    #[msg("Reclaim grace period has not elapsed yet")]
+    ReclaimNotYetAllowed,
+
+    #[msg("Reclaim grace period cannot be negative")]
+    InvalidGracePeriod,
+
+    #[msg("Metadata symbol exceeds maximum length")]
+    SymbolTooLong,
+
+    #[msg("Metadata URI exceeds maximum length")]
+    UriTooLong,
+
+    #[msg("Only the deploy authority can initialize the config")]
+    UnauthorizedInitializer,
+
+    #[msg("Cannot gift a capsule to yourself")]
+    CannotGiftToSelf,
+
+    // No longer returned; kept so the codes of later variants stay the same
+    #[msg("Too many early unlock attempts; try again after the cooldown")]
+    UnlockCooldownActive,
+
+    #[msg("Expiry must be in the future and after the unlock date")]
+    InvalidExpiry,
+
+    #[msg("Capsule has not expired yet")]
+    CapsuleNotExpired,
+
+    #[msg("Unlock grace must be between 0 and one hour")]
+    InvalidUnlockGrace,
+
+    #[msg("Text capsules cannot be minted")]
+    CannotMintTextCapsule,
+
+    #[msg("The capsule's mint account must be provided to change its unlock date")]
+    MintRequiredForUpdate,
+
+    #[msg("A witnessed capsule needs between 1 and the maximum number of witnesses")]
+    InvalidWitnessCount,
+
+    #[msg("Every witness must sign the transaction")]
+    WitnessMustSign,
+
+    #[msg("Key has already witnessed this capsule")]
+    DuplicateWitness,
+
+    #[msg("Capsule already has the maximum number of witnesses")]
+    TooManyWitnesses,
+
+    #[msg("Too many encrypted URLs (max 10 per capsule)")]
+    TooManyUrls,
+
+    #[msg("Encrypted URL index is out of range")]
+    InvalidUrlIndex,
+
+    #[msg("Account is not the config of this program")]
+    InvalidConfigAccount,
+
+    #[msg("Capsule still has likes; reactors must unlike it before it can be closed")]
+    CapsuleHasReactions,
+
+    #[msg("Capsule still has subscribers; they must unsubscribe before it can be closed")]
+    CapsuleHasSubscribers,
+
+    #[msg("Minted capsules cannot be reclaimed; the token holder keeps it")]
+    CannotReclaimMintedCapsule,
+
+    #[msg("Capsule has been passed on since the creator transferred it")]
+    CapsuleTransferredOnward,
 }
\ No newline at end of file
@@ -1,4 +1,11 @@
 use anchor_lang::prelude::*;
+use crate::state::{CapsuleCategory, CapsuleType};
+
+// Bits of CapsuleUpdated::change_mask
+pub const CHANGE_CONTENT: u8 = 1 << 0;
+pub const CHANGE_DATE: u8 = 1 << 1;
+pub const CHANGE_URL: u8 = 1 << 2;
+pub const CHANGE_TITLE: u8 = 1 << 3;
 
 #[event]
 pub struct CapsuleCreated {
@@ -6,6 +13,20 @@ pub struct CapsuleCreated {
     pub creator: Pubkey,
     pub title: String,
     pub unlock_date: i64,
+    pub content_hash: [u8; 32],
+    pub locked_hash: bool,
+    pub category: CapsuleCategory,
+    pub capsule_type: CapsuleType,
+    pub fee_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GiftCreated {
+    pub capsule: Pubkey,
+    pub sender: Pubkey,
+    pub recipient: Pubkey,
+    pub unlock_date: i64,
     pub timestamp: i64,
 }
 
@@ -14,8 +35,23 @@ pub struct CapsuleUpdated {
     pub capsule: Pubkey,
     pub updater: Pubkey,
     pub new_unlock_date: Option<i64>,
+    pub title_updated: bool,
     pub content_updated: bool,
     pub url_updated: bool,
+    pub new_category: Option<CapsuleCategory>,
+    pub new_is_public: Option<bool>,
+    pub removed_url_indices: Vec<u8>,
+    pub added_url_indices: Vec<u8>,
+    pub timestamp: i64,
+    pub change_mask: u8, // CHANGE_* bits mirroring the flags above, for compact filtering
+}
+
+#[event]
+pub struct CapsuleAppended {
+    pub capsule: Pubkey,
+    pub updater: Pubkey,
+    pub appended_length: u32,
+    pub content_length: u32,
     pub timestamp: i64,
 }
 
@@ -23,6 +59,21 @@ pub struct CapsuleUpdated {
 pub struct CapsuleUnlocked {
     pub capsule: Pubkey,
     pub unlocker: Pubkey,
+    pub recipient: Option<Pubkey>,
+    pub creator: Pubkey,
+    pub owner: Pubkey,
+    pub title: String,
+    pub effective_unlock_date: i64, // unlock_date plus the config unlock grace at the time of unlocking
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnlockCountdown {
+    pub capsule: Pubkey,
+    pub caller: Pubkey,
+    pub seconds_remaining: i64, // Zero or negative once the capsule can be unlocked
+    pub ready: bool,
+    pub authorized: bool,
     pub timestamp: i64,
 }
 
@@ -30,6 +81,32 @@ pub struct CapsuleUnlocked {
 pub struct CapsuleClosed {
     pub capsule: Pubkey,
     pub closer: Pubkey,
+    pub rent_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BatchClosed {
+    pub owner: Pubkey,
+    pub closed: u8,
+    pub skipped: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExpiryChanged {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleReaped {
+    pub capsule: Pubkey,
+    pub creator: Pubkey,
+    pub reaper: Pubkey,
+    pub expires_at: i64,
     pub timestamp: i64,
 }
 
@@ -39,5 +116,280 @@ pub struct CapsuleTransferred {
     pub from: Pubkey,
     pub to: Pubkey,
     pub mint: Option<Pubkey>,
+    pub fee_paid: u64,
+    pub timestamp: i64,
+    pub delegate_cleared: bool,
+}
+
+#[event]
+pub struct ProgramPauseChanged {
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleForceUnlocked {
+    pub capsule: Pubkey,
+    pub authority: Pubkey,
+    pub unlock_date: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub authority: Pubkey,
+    pub transfer_fee_lamports: u64,
+    pub create_fee_lamports: u64,
+    pub treasury: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigClosed {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub total_capsules: u64,
+    pub forced: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleArchived {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleUnarchived {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoOwnerAdded {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub co_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoOwnerRemoved {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub co_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RateLimitUpdated {
+    pub authority: Pubkey,
+    pub creation_window_seconds: i64,
+    pub max_per_window: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferPolicyChanged {
+    pub authority: Pubkey,
+    pub allow_locked_transfer: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CloseRentPolicyChanged {
+    pub authority: Pubkey,
+    pub rent_to_creator_on_close: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateChanged {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub old_delegate: Option<Pubkey>,
+    pub new_delegate: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleMigrated {
+    pub capsule: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleDiscarded {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub unlock_date: i64,
+    pub rent_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleLiked {
+    pub capsule: Pubkey,
+    pub reactor: Pubkey,
+    pub like_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleUnliked {
+    pub capsule: Pubkey,
+    pub reactor: Pubkey,
+    pub like_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccessGranted {
+    pub capsule: Pubkey,
+    pub requester: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigSnapshot {
+    pub authority: Pubkey,
+    pub total_capsules: u64,
+    pub version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Subscribed {
+    pub capsule: Pubkey,
+    pub subscriber: Pubkey,
+    pub unlock_date: i64,
+    pub subscriber_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Unsubscribed {
+    pub capsule: Pubkey,
+    pub subscriber: Pubkey,
+    pub subscriber_count: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleAddressDerived {
+    pub creator: Pubkey,
+    pub id: u64,
+    pub capsule: Pubkey,
+    pub bump: u8,
+}
+
+#[event]
+pub struct CapsuleReady {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub recipient: Option<Pubkey>,
+    pub unlock_date: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferInitiated {
+    pub capsule: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferAccepted {
+    pub capsule: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub fee_paid: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TransferCancelled {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LimitsUpdated {
+    pub authority: Pubkey,
+    pub max_title: u16,
+    pub max_content: u16,
+    pub max_url: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ContentRevealed {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub content_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapsuleReclaimed {
+    pub capsule: Pubkey,
+    pub creator: Pubkey,
+    pub previous_owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReclaimGracePeriodChanged {
+    pub authority: Pubkey,
+    pub reclaim_grace_period: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnlockGraceChanged {
+    pub authority: Pubkey,
+    pub unlock_grace_seconds: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MetadataSet {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub symbol: Option<String>,
+    pub uri: Option<String>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WitnessAdded {
+    pub capsule: Pubkey,
+    pub owner: Pubkey,
+    pub witness: Pubkey,
+    pub witness_count: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub config: Pubkey,
+    pub authority: Pubkey,
+    pub version: u8,
     pub timestamp: i64,
 }
\ No newline at end of file
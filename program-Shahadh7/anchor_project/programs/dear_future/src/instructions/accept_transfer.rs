@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::*, errors::ErrorCode, events::TransferAccepted};
+
+#[derive(Accounts)]
+pub struct AcceptTransfer<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(mut)]
+    pub new_owner: Signer<'info>,
+    
+    /// CHECK: Only receives lamports; address is validated against the config
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AcceptTransfer>) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
+    let clock = Clock::get()?;
+    let capsule = &mut ctx.accounts.capsule;
+    let new_owner_key = ctx.accounts.new_owner.key();
+    
+    // Only the pending owner named by initiate_transfer can accept
+    let pending_owner = capsule.pending_owner.ok_or(ErrorCode::NoPendingTransfer)?;
+    require_keys_eq!(pending_owner, new_owner_key, ErrorCode::NotPendingOwner);
+    
+    // The policy may have changed since the transfer was initiated
+    require!(
+        ctx.accounts.config.allow_locked_transfer || capsule.is_unlocked || capsule.mint.is_some(),
+        ErrorCode::CannotTransferLockedCapsule
+    );
+    
+    // The transfer fee is paid by the accepting owner, so cancelled transfers cost nothing
+    let fee = ctx.accounts.config.transfer_fee_lamports;
+    if fee > 0 {
+        require!(
+            ctx.accounts.new_owner.lamports() >= fee,
+            ErrorCode::InsufficientFeeBalance
+        );
+        
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.new_owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+    
+    let previous_owner = capsule.owner;
+    capsule.transfer_to(new_owner_key, clock.unix_timestamp);
+    
+    emit!(TransferAccepted {
+        capsule: capsule.key(),
+        from: previous_owner,
+        to: new_owner_key,
+        fee_paid: fee,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule transferred from {} to {}", previous_owner, new_owner_key);
+    
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::CoOwnerAdded};
+
+#[derive(Accounts)]
+pub struct AddCoOwner<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AddCoOwner>, co_owner: Pubkey) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(
+        co_owner != capsule.owner && !capsule.co_owners.contains(&co_owner),
+        ErrorCode::CoOwnerAlreadyExists
+    );
+    require!(
+        capsule.co_owners.len() < MAX_CO_OWNERS,
+        ErrorCode::TooManyCoOwners
+    );
+    
+    capsule.co_owners.push(co_owner);
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(CoOwnerAdded {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        co_owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Co-owner {} added to capsule {}", co_owner, capsule.key());
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::WitnessAdded};
+
+#[derive(Accounts)]
+pub struct AddWitness<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+    
+    pub witness: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AddWitness>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    // Witnessing an opened capsule would attest to nothing
+    require!(!capsule.is_unlocked, ErrorCode::CapsuleAlreadyUnlocked);
+    
+    let witness = ctx.accounts.witness.key();
+    capsule.add_witness(witness)?;
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(WitnessAdded {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        witness,
+        witness_count: capsule.witnesses.len() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Witness {} added to capsule {}", witness, capsule.key());
+    
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::CapsuleAppended, utils::validate_text};
+
+#[derive(Accounts)]
+pub struct AppendContent<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.can_be_updated_by(&updater.key()) @ ErrorCode::NotOwnerOrDelegate,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(mut)]
+    pub updater: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AppendContent>, extra: String) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
+    let capsule = &mut ctx.accounts.capsule;
+    require!(capsule.can_be_updated(), ErrorCode::CapsuleAlreadyUnlocked);
+    require!(!capsule.archived, ErrorCode::ArchivedCapsuleImmutable);
+    require!(!capsule.locked_hash, ErrorCode::ContentIsLocked);
+    require!(!extra.is_empty(), ErrorCode::NoContentProvided);
+    
+    // The combined text must still fit the current limit, same as a full replacement
+    require!(
+        capsule.content.len() + extra.len() <= ctx.accounts.config.content_limit(),
+        ErrorCode::ContentTooLong
+    );
+    validate_text(&extra)?;
+    
+    let clock = Clock::get()?;
+    capsule.content.push_str(&extra);
+    capsule.updated_at = clock.unix_timestamp;
+    capsule.last_modified_by = ctx.accounts.updater.key();
+    
+    emit!(CapsuleAppended {
+        capsule: capsule.key(),
+        updater: ctx.accounts.updater.key(),
+        appended_length: extra.len() as u32,
+        content_length: capsule.content.len() as u32,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Appended {} bytes to capsule {}", extra.len(), capsule.key());
+    
+    Ok(())
+}
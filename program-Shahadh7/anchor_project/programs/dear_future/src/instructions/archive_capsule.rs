@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::CapsuleArchived};
+
+#[derive(Accounts)]
+pub struct ArchiveCapsule<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ArchiveCapsule>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(!capsule.archived, ErrorCode::CapsuleAlreadyArchived);
+    
+    capsule.archived = true;
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(CapsuleArchived {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule archived: {}", capsule.key());
+    
+    Ok(())
+}
\ No newline at end of file
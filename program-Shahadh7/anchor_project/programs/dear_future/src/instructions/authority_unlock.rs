@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::CapsuleForceUnlocked};
+
+#[derive(Accounts)]
+pub struct AuthorityUnlock<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AuthorityUnlock>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    // Deliberately skips the unlock date check enforced by unlock_capsule
+    require!(!capsule.is_unlocked, ErrorCode::CapsuleAlreadyUnlocked);
+    
+    capsule.is_unlocked = true;
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(CapsuleForceUnlocked {
+        capsule: capsule.key(),
+        authority: ctx.accounts.authority.key(),
+        unlock_date: capsule.unlock_date,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule force unlocked by authority: {}", capsule.key());
+    
+    Ok(())
+}
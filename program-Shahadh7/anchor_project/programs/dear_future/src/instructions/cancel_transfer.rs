@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::TransferCancelled};
+
+#[derive(Accounts)]
+pub struct CancelTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelTransfer>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    let pending_owner = capsule.pending_owner.take().ok_or(ErrorCode::NoPendingTransfer)?;
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(TransferCancelled {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        pending_owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Pending transfer of capsule {} to {} cancelled", capsule.key(), pending_owner);
+    
+    Ok(())
+}
@@ -1,34 +1,56 @@
 use anchor_lang::prelude::*;
-use crate::{state::Capsule, errors::ErrorCode, events::CapsuleClosed};
+use crate::{state::{Capsule, Config}, errors::ErrorCode, events::CapsuleClosed};
 
 #[derive(Accounts)]
 pub struct CloseCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
         bump = capsule.bump,
-        constraint = capsule.owner == owner.key() @ ErrorCode::NotOwner,
-        close = owner,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
     )]
     pub capsule: Account<'info, Capsule>,
     
     #[account(mut)]
     pub owner: Signer<'info>,
+    
+    /// CHECK: Receives the rent when the config routes it back to the creator; address is validated against the capsule
+    #[account(
+        mut,
+        address = capsule.creator @ ErrorCode::NotCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
 }
 
 pub fn handler(ctx: Context<CloseCapsule>) -> Result<()> {
-    let capsule = &ctx.accounts.capsule;
     let clock = Clock::get()?;
     
-    require!(capsule.is_unlocked, ErrorCode::CannotCloseLockedCapsule);
+    require!(ctx.accounts.capsule.is_unlocked, ErrorCode::CannotCloseLockedCapsule);
+    // Reactions and subscriptions are closed through the capsule, so their rent would be stranded
+    require!(ctx.accounts.capsule.like_count == 0, ErrorCode::CapsuleHasReactions);
+    require!(ctx.accounts.capsule.subscriber_count == 0, ErrorCode::CapsuleHasSubscribers);
+    
+    // The creator paid the rent, so a transferred capsule can hand it back to them instead of the new owner
+    let rent_recipient = if ctx.accounts.config.rent_to_creator_on_close {
+        ctx.accounts.creator.to_account_info()
+    } else {
+        ctx.accounts.owner.to_account_info()
+    };
     
     emit!(CapsuleClosed {
-        capsule: capsule.key(),
+        capsule: ctx.accounts.capsule.key(),
         closer: ctx.accounts.owner.key(),
+        rent_recipient: rent_recipient.key(),
         timestamp: clock.unix_timestamp,
     });
     
-    msg!("Capsule closed: {}", capsule.key());
+    msg!("Capsule closed: {}", ctx.accounts.capsule.key());
     
-    Ok(())
-}
\ No newline at end of file
+    ctx.accounts.capsule.close(rent_recipient)
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::{BatchClosed, CapsuleClosed}};
+
+#[derive(Accounts)]
+pub struct CloseCapsulesBatch<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+// Capsules are passed as writable remaining accounts; still-locked ones are skipped rather than failing the batch,
+// as are capsules from other creators while the config routes close rent back to the creator
+// and capsules that still have likes or subscribers
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseCapsulesBatch<'info>>,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_CLOSE_BATCH,
+        ErrorCode::InvalidBatchSize
+    );
+    
+    let clock = Clock::get()?;
+    let owner = ctx.accounts.owner.key();
+    let mut closed: u8 = 0;
+    let mut skipped: u8 = 0;
+    
+    for capsule_info in ctx.remaining_accounts.iter() {
+        // Deserializing checks the program owner and discriminator, so only real capsules get here
+        let capsule = Account::<Capsule>::try_from(capsule_info)?;
+        require!(capsule.is_owned_by(&owner), ErrorCode::NotOwner);
+        
+        if !capsule.is_unlocked || capsule.like_count > 0 || capsule.subscriber_count > 0 {
+            skipped += 1;
+            continue;
+        }
+        if ctx.accounts.config.rent_to_creator_on_close && capsule.creator != owner {
+            skipped += 1;
+            continue;
+        }
+        
+        emit!(CapsuleClosed {
+            capsule: capsule.key(),
+            closer: owner,
+            rent_recipient: owner,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        capsule.close(ctx.accounts.owner.to_account_info())?;
+        closed += 1;
+    }
+    
+    emit!(BatchClosed {
+        owner,
+        closed,
+        skipped,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Closed {} capsules, skipped {}", closed, skipped);
+    
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::ConfigClosed};
+
+#[derive(Accounts)]
+pub struct CloseConfig<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+        close = authority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseConfig>, force: bool) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    // Closing resets the capsule counter on re-init, which would collide with existing capsule PDAs
+    require!(force || config.total_capsules == 0, ErrorCode::ConfigHasCapsules);
+    
+    emit!(ConfigClosed {
+        config: config.key(),
+        authority: ctx.accounts.authority.key(),
+        total_capsules: config.total_capsules,
+        forced: force,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Config closed with {} capsules", config.total_capsules);
+    
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::ErrorCode, events::CapsuleCreated};
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::*, errors::ErrorCode, events::CapsuleCreated, utils::{validate_text, validate_url}};
 
 #[derive(Accounts)]
 pub struct CreateCapsule<'info> {
@@ -10,11 +11,29 @@ pub struct CreateCapsule<'info> {
     )]
     pub config: Account<'info, Config>,
     
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorStats::INIT_SPACE,
+        seeds = [CreatorStats::SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorIndex::INIT_SPACE,
+        seeds = [CreatorIndex::SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+    
     #[account(
         init,
         payer = creator,
-        space = Capsule::INIT_SPACE,
-        seeds = [Capsule::SEED, creator.key().as_ref(), &config.total_capsules.to_le_bytes()],
+        space = 8 + Capsule::INIT_SPACE,
+        seeds = [Capsule::SEED, creator.key().as_ref(), &creator_stats.next_id.to_le_bytes()],
         bump
     )]
     pub capsule: Account<'info, Capsule>,
@@ -22,69 +41,244 @@ pub struct CreateCapsule<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
     
+    /// CHECK: Only receives lamports; address is validated against the config
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<CreateCapsule>,
-    title: String,
-    content: String,
-    unlock_date: i64,
-    encrypted_url: Option<String>,
-) -> Result<()> {
+/// Everything a new capsule is built from, shared by every instruction that creates one
+pub struct CreateCapsuleArgs {
+    pub title: String,
+    pub content: String,
+    pub unlock_date: i64,
+    pub encrypted_urls: Vec<String>,
+    pub recipient: Option<Pubkey>,
+    pub locked_hash: bool,
+    pub category: CapsuleCategory,
+    pub is_public: bool,
+    pub relock_date: Option<i64>,
+    pub content_cid: Option<String>,
+    pub access_hash: Option<[u8; 32]>,
+    pub sealed_content_hash: Option<[u8; 32]>,
+    pub capsule_type: CapsuleType,
+}
+
+impl CreateCapsuleArgs {
+    /// A plain personal capsule; callers override the optional fields they need
+    pub fn new(title: String, content: String, unlock_date: i64, encrypted_urls: Vec<String>, capsule_type: CapsuleType) -> Self {
+        Self {
+            title,
+            content,
+            unlock_date,
+            encrypted_urls,
+            recipient: None,
+            locked_hash: false,
+            category: CapsuleCategory::Personal,
+            is_public: false,
+            relock_date: None,
+            content_cid: None,
+            access_hash: None,
+            sealed_content_hash: None,
+            capsule_type,
+        }
+    }
+    
+    /// The single place a new capsule's fields are set; shared by every create instruction
+    /// so a field added to `Capsule` only needs initializing here
+    pub fn into_capsule(self, creator: Pubkey, id: u64, bump: u8, schema_version: u8, now: i64) -> Capsule {
+        let sealed = self.sealed_content_hash.is_some();
+        Capsule {
+            creator,
+            owner: creator, // Initially, creator is the owner
+            recipient: self.recipient,
+            id,
+            unlock_date: self.unlock_date,
+            relock_date: self.relock_date,
+            created_at: now,
+            updated_at: now,
+            transferred_at: None,
+            mint: None,
+            mint_creator: None,
+            // A sealed capsule commits to the hash of its title and future content; editing is then locked
+            content_hash: self.sealed_content_hash.unwrap_or_else(|| Capsule::compute_content_hash(&self.title, &self.content)),
+            locked_hash: self.locked_hash || sealed,
+            category: self.category,
+            bump,
+            is_unlocked: false,
+            archived: false,
+            is_public: self.is_public,
+            view_count: 0,
+            title: self.title,
+            content: self.content,
+            encrypted_urls: self.encrypted_urls,
+            co_owners: Vec::new(),
+            delegate: None,
+            schema_version,
+            like_count: 0,
+            content_cid: self.content_cid,
+            access_hash: self.access_hash,
+            last_modified_by: creator,
+            ready_announced: false,
+            pending_owner: None,
+            sealed,
+            symbol: None,
+            uri: None,
+            reserved: [0; 10],
+            expires_at: None,
+            capsule_type: self.capsule_type,
+            witnesses: Vec::new(),
+            subscriber_count: 0,
+            previous_owner: None,
+        }
+    }
+}
+
+pub fn handler(mut ctx: Context<CreateCapsule>, args: CreateCapsuleArgs) -> Result<()> {
+    init_capsule(&mut ctx, args)
+}
+
+/// Shared by `create_capsule` and `create_gift`; the creator starts out as the owner
+pub(crate) fn init_capsule(ctx: &mut Context<CreateCapsule>, args: CreateCapsuleArgs) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
     // Validate inputs first to fail fast
-    require!(
-        title.len() <= MAX_TITLE_LENGTH,
-        ErrorCode::TitleTooLong
-    );
+    let clock = Clock::get()?;
+    validate_capsule_inputs(&ctx.accounts.config, &args, clock.unix_timestamp)?;
+    if let Some(relock_date) = args.relock_date {
+        require!(relock_date > args.unlock_date, ErrorCode::InvalidRelockDate);
+    }
     
-    require!(
-        content.len() <= MAX_CONTENT_LENGTH,
-        ErrorCode::ContentTooLong
-    );
+    let config = &ctx.accounts.config;
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    creator_stats.creator = ctx.accounts.creator.key();
+    creator_stats.bump = ctx.bumps.creator_stats;
+    creator_stats.record_creations(1, clock.unix_timestamp, config.creation_window_seconds, config.max_per_window)?;
     
-    if let Some(ref url) = encrypted_url {
+    // Collect the creation fee into the treasury
+    let fee = config.create_fee_lamports;
+    if fee > 0 {
         require!(
-            url.len() <= MAX_URL_LENGTH,
-            ErrorCode::UrlTooLong
+            ctx.accounts.creator.lamports() >= fee,
+            ErrorCode::InsufficientFeeBalance
         );
+        
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
     }
     
-    let clock = Clock::get()?;
-    require!(
-        unlock_date > clock.unix_timestamp,
-        ErrorCode::UnlockDateMustBeFuture
-    );
-    
-    // Initialize capsule directly without intermediate variables
+    let id = creator_stats.allocate_id()?;
+    let schema_version = config.version;
     let capsule = &mut ctx.accounts.capsule;
-    capsule.creator = ctx.accounts.creator.key();
-    capsule.owner = ctx.accounts.creator.key(); // Initially, creator is the owner
-    capsule.id = ctx.accounts.config.total_capsules;
-    capsule.title = title;
-    capsule.content = content;
-    capsule.encrypted_url = encrypted_url;
-    capsule.unlock_date = unlock_date;
-    capsule.is_unlocked = false;
-    capsule.mint = None;
-    capsule.mint_creator = None;
-    capsule.transferred_at = None;
-    capsule.created_at = clock.unix_timestamp;
-    capsule.updated_at = clock.unix_timestamp;
-    capsule.bump = ctx.bumps.capsule;
+    capsule.set_inner(args.into_capsule(ctx.accounts.creator.key(), id, ctx.bumps.capsule, schema_version, clock.unix_timestamp));
+    
+    let creator_index = &mut ctx.accounts.creator_index;
+    creator_index.creator = ctx.accounts.creator.key();
+    creator_index.bump = ctx.bumps.creator_index;
+    creator_index.record(capsule.key());
     
     // Update global counter
-    ctx.accounts.config.total_capsules = ctx.accounts.config.total_capsules.checked_add(1).unwrap();
+    ctx.accounts.config.total_capsules = ctx.accounts.config.total_capsules.checked_add(1).ok_or(ErrorCode::CounterOverflow)?;
     
     emit!(CapsuleCreated {
         capsule: capsule.key(),
         creator: ctx.accounts.creator.key(),
         title: capsule.title.clone(),
-        unlock_date,
+        unlock_date: capsule.unlock_date,
+        content_hash: capsule.content_hash,
+        locked_hash: capsule.locked_hash,
+        category: capsule.category,
+        capsule_type: capsule.capsule_type,
+        fee_paid: fee,
         timestamp: clock.unix_timestamp,
     });
     
     msg!("Capsule created: {}", capsule.key());
     
+    Ok(())
+}
+
+/// Shared by `create_capsule` and `create_capsules_batch`
+pub fn validate_capsule_inputs(config: &Config, args: &CreateCapsuleArgs, now: i64) -> Result<()> {
+    let title = args.title.as_str();
+    let content = args.content.as_str();
+    let content_cid = args.content_cid.as_deref();
+    let unlock_date = args.unlock_date;
+    let encrypted_urls = &args.encrypted_urls;
+    let sealed = args.sealed_content_hash.is_some();
+    
+    require!(
+        title.len() <= config.title_limit(),
+        ErrorCode::TitleTooLong
+    );
+    
+    require!(
+        content.len() <= config.content_limit(),
+        ErrorCode::ContentTooLong
+    );
+    
+    validate_text(title)?;
+    validate_text(content)?;
+    
+    if let Some(cid) = content_cid {
+        require!(
+            cid.len() <= MAX_CID_LENGTH,
+            ErrorCode::ContentCidTooLong
+        );
+    }
+    
+    if sealed {
+        // Nothing readable may be stored until a sealed capsule is revealed
+        require!(
+            content.is_empty() && content_cid.is_none() && encrypted_urls.is_empty(),
+            ErrorCode::SealedContentProvided
+        );
+    } else {
+        // Content may be left empty as long as the full text lives off-chain
+        let has_cid = content_cid.is_some_and(|cid| !cid.is_empty());
+        require!(
+            !content.is_empty() || has_cid,
+            ErrorCode::NoContentProvided
+        );
+        require!(
+            has_cid || content.len() >= MIN_CONTENT_LENGTH,
+            ErrorCode::ContentTooShort
+        );
+    }
+    
+    require!(
+        encrypted_urls.len() <= MAX_URLS,
+        ErrorCode::TooManyUrls
+    );
+    
+    for url in encrypted_urls.iter() {
+        require!(
+            url.len() <= config.url_limit(),
+            ErrorCode::UrlTooLong
+        );
+        validate_url(url)?;
+    }
+    
+    require!(
+        unlock_date > now,
+        ErrorCode::UnlockDateMustBeFuture
+    );
+    require!(
+        unlock_date <= now.saturating_add(MAX_UNLOCK_HORIZON),
+        ErrorCode::UnlockDateTooFar
+    );
+    
     Ok(())
 }
\ No newline at end of file
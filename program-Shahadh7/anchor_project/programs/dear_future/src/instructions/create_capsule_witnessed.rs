@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::WitnessAdded, instructions::create_capsule::{init_capsule, CreateCapsuleArgs}};
+
+// Witnesses are passed as remaining accounts and must all sign. The content hash is
+// locked so the text they attest to cannot be edited afterwards.
+pub fn handler<'info>(
+    mut ctx: Context<'_, '_, 'info, 'info, CreateCapsule<'info>>,
+    title: String,
+    content: String,
+    unlock_date: i64,
+    encrypted_urls: Vec<String>,
+    capsule_type: CapsuleType,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_WITNESSES,
+        ErrorCode::InvalidWitnessCount
+    );
+    let mut witnesses = Vec::with_capacity(ctx.remaining_accounts.len());
+    for witness_info in ctx.remaining_accounts.iter() {
+        require!(witness_info.is_signer, ErrorCode::WitnessMustSign);
+        witnesses.push(witness_info.key());
+    }
+    
+    init_capsule(&mut ctx, CreateCapsuleArgs {
+        locked_hash: true,
+        ..CreateCapsuleArgs::new(title, content, unlock_date, encrypted_urls, capsule_type)
+    })?;
+    
+    let capsule = &mut ctx.accounts.capsule;
+    for witness in witnesses {
+        capsule.add_witness(witness)?;
+        
+        emit!(WitnessAdded {
+            capsule: capsule.key(),
+            owner: capsule.owner,
+            witness,
+            witness_count: capsule.witnesses.len() as u8,
+            timestamp: capsule.created_at,
+        });
+    }
+    
+    msg!("Capsule {} created with {} witnesses", capsule.key(), capsule.witnesses.len());
+    
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer};
+use crate::{state::*, errors::ErrorCode, events::CapsuleCreated};
+use super::create_capsule::{validate_capsule_inputs, CreateCapsuleArgs};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CapsuleInput {
+    pub title: String,
+    pub content: String,
+    pub unlock_date: i64,
+    pub encrypted_urls: Vec<String>,
+    pub capsule_type: CapsuleType,
+}
+
+#[derive(Accounts)]
+pub struct CreateCapsulesBatch<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorStats::INIT_SPACE,
+        seeds = [CreatorStats::SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_stats: Account<'info, CreatorStats>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + CreatorIndex::INIT_SPACE,
+        seeds = [CreatorIndex::SEED, creator.key().as_ref()],
+        bump
+    )]
+    pub creator_index: Account<'info, CreatorIndex>,
+    
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    /// CHECK: Only receives lamports; address is validated against the config
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateCapsulesBatch<'info>>,
+    capsules: Vec<CapsuleInput>,
+) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    require!(
+        !capsules.is_empty() && capsules.len() <= MAX_BATCH,
+        ErrorCode::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == capsules.len(),
+        ErrorCode::InvalidBatchAccounts
+    );
+    
+    let clock = Clock::get()?;
+    let creator = ctx.accounts.creator.key();
+    
+    let config = &ctx.accounts.config;
+    let creator_stats = &mut ctx.accounts.creator_stats;
+    creator_stats.creator = creator;
+    creator_stats.bump = ctx.bumps.creator_stats;
+    creator_stats.record_creations(capsules.len() as u32, clock.unix_timestamp, config.creation_window_seconds, config.max_per_window)?;
+    ctx.accounts.creator_index.creator = creator;
+    ctx.accounts.creator_index.bump = ctx.bumps.creator_index;
+    
+    // Collect the creation fee into the treasury
+    let fee = config.create_fee_lamports;
+    let total_fee = fee
+        .checked_mul(capsules.len() as u64)
+        .ok_or(ErrorCode::InsufficientFeeBalance)?;
+    if total_fee > 0 {
+        require!(
+            ctx.accounts.creator.lamports() >= total_fee,
+            ErrorCode::InsufficientFeeBalance
+        );
+        
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.creator.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            total_fee,
+        )?;
+    }
+    
+    let space = 8 + Capsule::INIT_SPACE;
+    let lamports = Rent::get()?.minimum_balance(space);
+    
+    for (input, capsule_info) in capsules.into_iter().zip(ctx.remaining_accounts.iter()) {
+        let args = CreateCapsuleArgs::new(input.title, input.content, input.unlock_date, input.encrypted_urls, input.capsule_type);
+        validate_capsule_inputs(&ctx.accounts.config, &args, clock.unix_timestamp)?;
+        
+        // Each capsule must be the next PDA in the creator's sequence, same as create_capsule
+        let id = ctx.accounts.creator_stats.allocate_id()?;
+        let id_bytes = id.to_le_bytes();
+        let (expected, bump) = derive_capsule_pda(&creator, id);
+        require_keys_eq!(capsule_info.key(), expected, ErrorCode::InvalidBatchAccounts);
+        
+        let signer_seeds: &[&[&[u8]]] = &[&[Capsule::SEED, creator.as_ref(), &id_bytes, &[bump]]];
+        let system_program = ctx.accounts.system_program.to_account_info();
+        let current_lamports = capsule_info.lamports();
+        
+        if current_lamports == 0 {
+            create_account(
+                CpiContext::new_with_signer(
+                    system_program,
+                    CreateAccount {
+                        from: ctx.accounts.creator.to_account_info(),
+                        to: capsule_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                lamports,
+                space as u64,
+                ctx.program_id,
+            )?;
+        } else {
+            // Someone already sent lamports to the PDA, which makes create_account fail;
+            // top up the rent shortfall and allocate/assign instead, as Anchor's init does
+            let shortfall = lamports.saturating_sub(current_lamports);
+            if shortfall > 0 {
+                transfer(
+                    CpiContext::new(
+                        system_program.clone(),
+                        Transfer {
+                            from: ctx.accounts.creator.to_account_info(),
+                            to: capsule_info.clone(),
+                        },
+                    ),
+                    shortfall,
+                )?;
+            }
+            allocate(
+                CpiContext::new_with_signer(
+                    system_program.clone(),
+                    Allocate {
+                        account_to_allocate: capsule_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                space as u64,
+            )?;
+            assign(
+                CpiContext::new_with_signer(
+                    system_program,
+                    Assign {
+                        account_to_assign: capsule_info.clone(),
+                    },
+                    signer_seeds,
+                ),
+                ctx.program_id,
+            )?;
+        }
+        
+        let capsule = args.into_capsule(creator, id, bump, ctx.accounts.config.version, clock.unix_timestamp);
+        {
+            let mut data = capsule_info.try_borrow_mut_data()?;
+            capsule.try_serialize(&mut &mut data[..])?;
+        }
+        
+        ctx.accounts.creator_index.record(capsule_info.key());
+        ctx.accounts.config.total_capsules = ctx.accounts.config.total_capsules.checked_add(1).ok_or(ErrorCode::CounterOverflow)?;
+        
+        emit!(CapsuleCreated {
+            capsule: capsule_info.key(),
+            creator,
+            title: capsule.title,
+            unlock_date: capsule.unlock_date,
+            content_hash: capsule.content_hash,
+            locked_hash: capsule.locked_hash,
+            category: capsule.category,
+            capsule_type: capsule.capsule_type,
+            fee_paid: fee,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("Capsule created: {}", capsule_info.key());
+    }
+    
+    Ok(())
+}
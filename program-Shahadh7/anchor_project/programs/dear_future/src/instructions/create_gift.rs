@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::GiftCreated, instructions::create_capsule::{init_capsule, CreateCapsuleArgs}};
+
+// Gifts reuse the CreateCapsule accounts; the sender pays and stays the creator,
+// but ownership (and with it the right to update) moves to the recipient at once.
+// transferred_at stays unset, so the sender cannot reclaim the gift later.
+pub fn handler(
+    mut ctx: Context<CreateCapsule>,
+    recipient: Pubkey,
+    title: String,
+    content: String,
+    unlock_date: i64,
+    encrypted_urls: Vec<String>,
+    capsule_type: CapsuleType,
+) -> Result<()> {
+    let sender = ctx.accounts.creator.key();
+    require!(recipient != sender, ErrorCode::CannotGiftToSelf);
+    
+    init_capsule(&mut ctx, CreateCapsuleArgs {
+        recipient: Some(recipient),
+        category: CapsuleCategory::Gift,
+        ..CreateCapsuleArgs::new(title, content, unlock_date, encrypted_urls, capsule_type)
+    })?;
+    
+    let capsule = &mut ctx.accounts.capsule;
+    capsule.owner = recipient;
+    
+    emit!(GiftCreated {
+        capsule: capsule.key(),
+        sender,
+        recipient,
+        unlock_date,
+        timestamp: capsule.created_at,
+    });
+    
+    msg!("Capsule {} gifted from {} to {}", capsule.key(), sender, recipient);
+    
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::{state::derive_capsule_pda, events::CapsuleAddressDerived};
+
+#[derive(Accounts)]
+pub struct DeriveCapsuleAddress {}
+
+pub fn handler(_ctx: Context<DeriveCapsuleAddress>, creator: Pubkey, id: u64) -> Result<()> {
+    let (capsule, bump) = derive_capsule_pda(&creator, id);
+    
+    emit!(CapsuleAddressDerived {
+        creator,
+        id,
+        capsule,
+        bump,
+    });
+    
+    Ok(())
+}
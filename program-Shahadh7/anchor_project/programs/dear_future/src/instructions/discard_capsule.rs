@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, Config}, errors::ErrorCode, events::CapsuleDiscarded};
+
+#[derive(Accounts)]
+pub struct DiscardCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    /// CHECK: Receives the rent when the config routes it back to the creator; address is validated against the capsule
+    #[account(
+        mut,
+        address = capsule.creator @ ErrorCode::NotCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<DiscardCapsule>, confirm: bool) -> Result<()> {
+    let capsule = &ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(confirm, ErrorCode::DiscardNotConfirmed);
+    // Unlocked capsules go through close_capsule instead
+    require!(!capsule.is_unlocked, ErrorCode::CapsuleAlreadyUnlocked);
+    require!(capsule.like_count == 0, ErrorCode::CapsuleHasReactions);
+    require!(capsule.subscriber_count == 0, ErrorCode::CapsuleHasSubscribers);
+    
+    // Same rent routing as close_capsule, so a new owner cannot keep the creator's rent by discarding
+    let rent_recipient = if ctx.accounts.config.rent_to_creator_on_close {
+        ctx.accounts.creator.to_account_info()
+    } else {
+        ctx.accounts.owner.to_account_info()
+    };
+    
+    emit!(CapsuleDiscarded {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        unlock_date: capsule.unlock_date,
+        rent_recipient: rent_recipient.key(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule discarded before unlock: {}", capsule.key());
+    
+    capsule.close(rent_recipient)
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::{Capsule, CapsuleStatus, Config};
+
+#[derive(Accounts)]
+pub struct GetCapsuleStatus<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+}
+
+pub fn handler(ctx: Context<GetCapsuleStatus>) -> Result<CapsuleStatus> {
+    let clock = Clock::get()?;
+    
+    Ok(ctx.accounts.capsule.status(clock.unix_timestamp, ctx.accounts.config.unlock_grace_seconds))
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, events::ConfigSnapshot};
+
+#[derive(Accounts)]
+pub struct GetConfig<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn handler(ctx: Context<GetConfig>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    
+    emit!(ConfigSnapshot {
+        authority: config.authority,
+        total_capsules: config.total_capsules,
+        version: config.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
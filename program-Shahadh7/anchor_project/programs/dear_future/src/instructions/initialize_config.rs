@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::Config;
+use crate::{errors::ErrorCode, state::Config};
 
 #[derive(Accounts)]
 pub struct InitializeConfig<'info> {
@@ -12,7 +12,10 @@ pub struct InitializeConfig<'info> {
     )]
     pub config: Account<'info, Config>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = Config::can_be_initialized_by(&authority.key()) @ ErrorCode::UnauthorizedInitializer,
+    )]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
@@ -20,11 +23,7 @@ pub struct InitializeConfig<'info> {
 
 pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
     let config = &mut ctx.accounts.config;
-    
-    config.authority = ctx.accounts.authority.key();
-    config.total_capsules = 0;
-    config.version = 1;
-    config.reserved = [0; 31];
+    config.set_inner(Config::with_defaults(ctx.accounts.authority.key()));
     
     msg!("Config initialized with authority: {}", config.authority);
     
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::TransferInitiated};
+
+#[derive(Accounts)]
+pub struct InitiateTransfer<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.can_be_transferred(&current_owner.key()) @ ErrorCode::NotOwner
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub current_owner: Signer<'info>,
+    
+    /// CHECK: New owner can be any valid public key; it must sign accept_transfer to take ownership
+    pub new_owner: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<InitiateTransfer>) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
+    let clock = Clock::get()?;
+    let capsule = &mut ctx.accounts.capsule;
+    let new_owner_key = ctx.accounts.new_owner.key();
+    let current_owner_key = ctx.accounts.current_owner.key();
+    
+    // Cannot transfer to the same owner
+    require!(
+        new_owner_key != current_owner_key,
+        ErrorCode::CannotTransferToSelf
+    );
+    
+    // Same locked-capsule policy as transfer_capsule; checked again on accept
+    require!(
+        ctx.accounts.config.allow_locked_transfer || capsule.is_unlocked || capsule.mint.is_some(),
+        ErrorCode::CannotTransferLockedCapsule
+    );
+    
+    // A new initiation replaces any earlier pending owner
+    capsule.pending_owner = Some(new_owner_key);
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(TransferInitiated {
+        capsule: capsule.key(),
+        from: current_owner_key,
+        to: new_owner_key,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule transfer from {} to {} awaiting acceptance", current_owner_key, new_owner_key);
+    
+    Ok(())
+}
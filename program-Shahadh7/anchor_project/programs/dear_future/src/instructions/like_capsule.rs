@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, CapsuleReaction}, errors::ErrorCode, events::CapsuleLiked};
+
+#[derive(Accounts)]
+pub struct LikeCapsule<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(
+        init,
+        payer = reactor,
+        space = 8 + CapsuleReaction::INIT_SPACE,
+        seeds = [CapsuleReaction::SEED, reactor.key().as_ref(), capsule.key().as_ref()],
+        bump
+    )]
+    pub capsule_reaction: Account<'info, CapsuleReaction>,
+    
+    #[account(mut)]
+    pub reactor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<LikeCapsule>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(capsule.is_public && capsule.is_unlocked, ErrorCode::CapsuleNotPublic);
+    require!(!capsule.is_relocked(clock.unix_timestamp), ErrorCode::CapsuleRelocked);
+    
+    let capsule_reaction = &mut ctx.accounts.capsule_reaction;
+    capsule_reaction.reactor = ctx.accounts.reactor.key();
+    capsule_reaction.capsule = capsule.key();
+    capsule_reaction.bump = ctx.bumps.capsule_reaction;
+    
+    capsule.like_count = capsule.like_count.saturating_add(1);
+    
+    emit!(CapsuleLiked {
+        capsule: capsule.key(),
+        reactor: ctx.accounts.reactor.key(),
+        like_count: capsule.like_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
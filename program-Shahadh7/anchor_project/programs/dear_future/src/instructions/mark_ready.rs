@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, Config}, errors::ErrorCode, events::CapsuleReady};
+
+#[derive(Accounts)]
+pub struct MarkReady<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+}
+
+pub fn handler(ctx: Context<MarkReady>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(!capsule.is_unlocked, ErrorCode::CapsuleAlreadyUnlocked);
+    require!(
+        capsule.is_ready_to_unlock(clock.unix_timestamp, ctx.accounts.config.unlock_grace_seconds),
+        ErrorCode::CapsuleNotReadyToUnlock
+    );
+    require!(!capsule.ready_announced, ErrorCode::ReadyAlreadyAnnounced);
+    
+    capsule.ready_announced = true;
+    
+    emit!(CapsuleReady {
+        capsule: capsule.key(),
+        owner: capsule.owner,
+        recipient: capsule.recipient,
+        unlock_date: capsule.unlock_date,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule ready to unlock: {}", capsule.key());
+    
+    Ok(())
+}
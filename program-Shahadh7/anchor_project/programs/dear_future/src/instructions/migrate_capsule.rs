@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::{Capsule, CapsuleType, CapsuleV0, Config, ORIGINAL_SCHEMA_VERSION}, errors::ErrorCode, events::CapsuleMigrated};
+use super::create_capsule::CreateCapsuleArgs;
+
+#[derive(Accounts)]
+pub struct MigrateCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// CHECK: May still use an older, shorter layout; the discriminator and PDA are validated in the handler
+    #[account(
+        mut,
+        owner = crate::ID @ ErrorCode::InvalidCapsuleAccount,
+    )]
+    pub capsule: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Maps a capsule from the original layout onto the current one; fields it never had start from
+/// the same defaults as a new capsule
+fn upgrade_original(legacy: CapsuleV0, schema_version: u8) -> Capsule {
+    // Original capsules could all be minted, so only a minted one is marked as an NFT and the rest stay mintable
+    let capsule_type = if legacy.mint.is_some() { CapsuleType::Nft } else { CapsuleType::Media };
+    let args = CreateCapsuleArgs::new(
+        legacy.title,
+        legacy.content,
+        legacy.unlock_date,
+        legacy.encrypted_url.into_iter().collect(),
+        capsule_type,
+    );
+    let mut capsule = args.into_capsule(legacy.creator, legacy.id, legacy.bump, schema_version, legacy.created_at);
+    capsule.owner = legacy.owner;
+    capsule.updated_at = legacy.updated_at;
+    capsule.transferred_at = legacy.transferred_at;
+    capsule.mint = legacy.mint;
+    capsule.mint_creator = legacy.mint_creator;
+    capsule.is_unlocked = legacy.is_unlocked;
+    capsule
+}
+
+pub fn handler(ctx: Context<MigrateCapsule>) -> Result<()> {
+    let capsule_info = ctx.accounts.capsule.to_account_info();
+    let clock = Clock::get()?;
+    let target_version = ctx.accounts.config.version;
+    let space = 8 + Capsule::INIT_SPACE;
+    
+    {
+        let data = capsule_info.try_borrow_data()?;
+        if data.len() < 8 || &data[..8] != Capsule::DISCRIMINATOR {
+            return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch.into());
+        }
+    }
+    // Original capsules were always allocated at exactly this size; later layouts only append fields
+    let is_original = capsule_info.data_len() == 8 + CapsuleV0::INIT_SPACE;
+    
+    // Grow older accounts to the current layout first, topping up rent from the payer;
+    // appended fields read back as zero (None, false, 0)
+    if capsule_info.data_len() < space {
+        let rent_due = Rent::get()?
+            .minimum_balance(space)
+            .saturating_sub(capsule_info.lamports());
+        if rent_due > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: capsule_info.clone(),
+                    },
+                ),
+                rent_due,
+            )?;
+        }
+        capsule_info.realloc(space, true)?;
+    }
+    
+    let (mut capsule, from_version) = {
+        let data = capsule_info.try_borrow_data()?;
+        if is_original {
+            let legacy = CapsuleV0::deserialize(&mut &data[8..])?;
+            (upgrade_original(legacy, target_version), ORIGINAL_SCHEMA_VERSION)
+        } else {
+            let capsule = Capsule::try_deserialize(&mut &data[..])?;
+            let from_version = capsule.schema_version;
+            (capsule, from_version)
+        }
+    };
+    
+    let expected = Pubkey::create_program_address(
+        &[Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes(), &[capsule.bump]],
+        ctx.program_id,
+    )
+    .map_err(|_| ErrorCode::InvalidCapsuleAccount)?;
+    require_keys_eq!(capsule_info.key(), expected, ErrorCode::InvalidCapsuleAccount);
+    require!(from_version < target_version, ErrorCode::AlreadyMigrated);
+    
+    capsule.schema_version = target_version;
+    if capsule.last_modified_by == Pubkey::default() {
+        capsule.last_modified_by = capsule.creator;
+    }
+    capsule.try_serialize(&mut &mut capsule_info.try_borrow_mut_data()?[..])?;
+    
+    emit!(CapsuleMigrated {
+        capsule: capsule_info.key(),
+        from_version,
+        to_version: target_version,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule {} migrated from schema {} to {}", capsule_info.key(), from_version, target_version);
+    
+    Ok(())
+}
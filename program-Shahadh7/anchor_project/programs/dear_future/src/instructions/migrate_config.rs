@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::{state::{Config, ConfigV0, CURRENT_SCHEMA_VERSION}, errors::ErrorCode, events::ConfigMigrated};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// CHECK: Still uses the original 72-byte layout; the discriminator and authority are validated in the handler
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        owner = crate::ID @ ErrorCode::InvalidConfigAccount,
+    )]
+    pub config: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.config.to_account_info();
+    let space = 8 + Config::INIT_SPACE;
+    require!(config_info.data_len() < space, ErrorCode::AlreadyMigrated);
+    
+    let legacy = {
+        let data = config_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && &data[..8] == Config::DISCRIMINATOR,
+            ErrorCode::InvalidConfigAccount
+        );
+        ConfigV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.authority, ctx.accounts.authority.key(), ErrorCode::UnauthorizedAuthority);
+    
+    // Grow the account to the current layout, topping up rent from the authority
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(config_info.lamports());
+    if rent_due > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: config_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    config_info.realloc(space, true)?;
+    
+    // Everything added after the original layout starts from the same defaults as a fresh config
+    let mut config = Config::with_defaults(legacy.authority);
+    config.total_capsules = legacy.total_capsules;
+    // The layout changed, so capsules can now be migrated up to the current schema as well
+    config.version = CURRENT_SCHEMA_VERSION;
+    config.paused = legacy.reserved[0] != 0;
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+    
+    emit!(ConfigMigrated {
+        config: config_info.key(),
+        authority: legacy.authority,
+        version: config.version,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Config migrated to the current layout");
+    
+    Ok(())
+}
@@ -1,13 +1,101 @@
 pub use initialize_config::*;
 pub use create_capsule::*;
+pub use create_gift::*;
+pub use create_capsule_witnessed::*;
+pub use create_capsules_batch::*;
 pub use update_capsule::*;
+pub use append_content::*;
 pub use unlock_capsule::*;
+pub use mark_ready::*;
+pub use reveal_content::*;
 pub use close_capsule::*;
+pub use close_capsules_batch::*;
+pub use set_expiry::*;
+pub use reap_expired::*;
 pub use transfer_capsule::*;
+pub use initiate_transfer::*;
+pub use accept_transfer::*;
+pub use cancel_transfer::*;
+pub use reclaim_capsule::*;
+pub use set_pause::*;
+pub use authority_unlock::*;
+pub use set_fee_config::*;
+pub use close_config::*;
+pub use set_authority::*;
+pub use set_rate_limit::*;
+pub use set_limits::*;
+pub use set_transfer_policy::*;
+pub use set_close_rent_policy::*;
+pub use set_reclaim_grace_period::*;
+pub use set_unlock_grace::*;
+pub use archive_capsule::*;
+pub use unarchive_capsule::*;
+pub use register_view::*;
+pub use get_capsule_status::*;
+pub use simulate_unlock::*;
+pub use get_config::*;
+pub use derive_capsule_address::*;
+pub use add_co_owner::*;
+pub use add_witness::*;
+pub use remove_co_owner::*;
+pub use set_delegate::*;
+pub use set_metadata::*;
+pub use migrate_capsule::*;
+pub use migrate_config::*;
+pub use discard_capsule::*;
+pub use like_capsule::*;
+pub use unlike_capsule::*;
+pub use subscribe::*;
+pub use unsubscribe::*;
+pub use verify_access::*;
 
 pub mod initialize_config;
 pub mod create_capsule;
+pub mod create_gift;
+pub mod create_capsule_witnessed;
+pub mod create_capsules_batch;
 pub mod update_capsule;
+pub mod append_content;
 pub mod unlock_capsule;
+pub mod mark_ready;
+pub mod reveal_content;
 pub mod close_capsule;
+pub mod close_capsules_batch;
+pub mod set_expiry;
+pub mod reap_expired;
 pub mod transfer_capsule;
+pub mod initiate_transfer;
+pub mod accept_transfer;
+pub mod cancel_transfer;
+pub mod reclaim_capsule;
+pub mod set_pause;
+pub mod authority_unlock;
+pub mod set_fee_config;
+pub mod close_config;
+pub mod set_authority;
+pub mod set_rate_limit;
+pub mod set_limits;
+pub mod set_transfer_policy;
+pub mod set_close_rent_policy;
+pub mod set_reclaim_grace_period;
+pub mod set_unlock_grace;
+pub mod archive_capsule;
+pub mod unarchive_capsule;
+pub mod register_view;
+pub mod get_capsule_status;
+pub mod simulate_unlock;
+pub mod get_config;
+pub mod derive_capsule_address;
+pub mod add_co_owner;
+pub mod add_witness;
+pub mod remove_co_owner;
+pub mod set_delegate;
+pub mod set_metadata;
+pub mod migrate_capsule;
+pub mod migrate_config;
+pub mod discard_capsule;
+pub mod like_capsule;
+pub mod unlike_capsule;
+pub mod subscribe;
+pub mod unsubscribe;
+pub mod verify_access;
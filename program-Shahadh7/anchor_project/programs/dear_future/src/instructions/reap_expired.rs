@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::CapsuleReaped};
+
+// Permissionless so a keeper can clean up abandoned capsules; the rent always goes to the creator
+#[derive(Accounts)]
+pub struct ReapExpired<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        close = creator,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    /// CHECK: Only receives the capsule rent; address is validated against the capsule
+    #[account(
+        mut,
+        address = capsule.creator @ ErrorCode::NotCreator,
+    )]
+    pub creator: UncheckedAccount<'info>,
+    
+    pub reaper: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReapExpired>) -> Result<()> {
+    let capsule = &ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(capsule.is_expired(clock.unix_timestamp), ErrorCode::CapsuleNotExpired);
+    require!(capsule.like_count == 0, ErrorCode::CapsuleHasReactions);
+    require!(capsule.subscriber_count == 0, ErrorCode::CapsuleHasSubscribers);
+    
+    emit!(CapsuleReaped {
+        capsule: capsule.key(),
+        creator: capsule.creator,
+        reaper: ctx.accounts.reaper.key(),
+        expires_at: capsule.expires_at.unwrap_or_default(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Expired capsule reaped: {}", capsule.key());
+    
+    Ok(())
+}
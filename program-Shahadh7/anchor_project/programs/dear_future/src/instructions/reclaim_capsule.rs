@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::CapsuleReclaimed};
+
+#[derive(Accounts)]
+pub struct ReclaimCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.creator == creator.key() @ ErrorCode::NotCreator,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub creator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ReclaimCapsule>) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
+    let clock = Clock::get()?;
+    let capsule = &mut ctx.accounts.capsule;
+    let creator_key = ctx.accounts.creator.key();
+    
+    require!(!capsule.is_owned_by(&creator_key), ErrorCode::CapsuleNotTransferred);
+    require!(!capsule.is_unlocked, ErrorCode::CapsuleAlreadyUnlocked);
+    require!(capsule.mint.is_none(), ErrorCode::CannotReclaimMintedCapsule);
+    // Only the creator's own transfer can be undone; a later owner's recipient keeps the capsule
+    require!(capsule.previous_owner == Some(creator_key), ErrorCode::CapsuleTransferredOnward);
+    
+    // Only once the current owner has held the capsule for the whole grace period
+    let transferred_at = capsule.transferred_at.ok_or(ErrorCode::CapsuleNotTransferred)?;
+    require!(
+        clock.unix_timestamp >= transferred_at.saturating_add(ctx.accounts.config.reclaim_grace_period),
+        ErrorCode::ReclaimNotYetAllowed
+    );
+    
+    let previous_owner = capsule.owner;
+    capsule.transfer_to(creator_key, clock.unix_timestamp);
+    
+    emit!(CapsuleReclaimed {
+        capsule: capsule.key(),
+        creator: creator_key,
+        previous_owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule {} reclaimed from {} by its creator", capsule.key(), previous_owner);
+    
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct RegisterView<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub viewer: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RegisterView>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    
+    require!(capsule.is_public && capsule.is_unlocked, ErrorCode::CapsuleNotPublic);
+    require!(
+        !capsule.is_relocked(Clock::get()?.unix_timestamp),
+        ErrorCode::CapsuleRelocked
+    );
+    
+    capsule.view_count = capsule.view_count.saturating_add(1);
+    
+    msg!("Capsule {} viewed by {}", capsule.key(), ctx.accounts.viewer.key());
+    
+    Ok(())
+}
\ No newline at end of file
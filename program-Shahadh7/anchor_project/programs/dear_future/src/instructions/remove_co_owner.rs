@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::CoOwnerRemoved};
+
+#[derive(Accounts)]
+pub struct RemoveCoOwner<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveCoOwner>, co_owner: Pubkey) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    let index = capsule
+        .co_owners
+        .iter()
+        .position(|key| *key == co_owner)
+        .ok_or(ErrorCode::CoOwnerNotFound)?;
+    
+    capsule.co_owners.remove(index);
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(CoOwnerRemoved {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        co_owner,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Co-owner {} removed from capsule {}", co_owner, capsule.key());
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::ContentRevealed, utils::validate_text};
+
+#[derive(Accounts)]
+pub struct RevealContent<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevealContent>, content: String) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    require!(capsule.sealed, ErrorCode::CapsuleNotSealed);
+    require!(capsule.is_unlocked, ErrorCode::CannotRevealLocked);
+    
+    require!(
+        content.len() <= ctx.accounts.config.content_limit(),
+        ErrorCode::ContentTooLong
+    );
+    validate_text(&content)?;
+    
+    // The hash committed at creation covers the title and the content
+    require!(
+        Capsule::compute_content_hash(&capsule.title, &content) == capsule.content_hash,
+        ErrorCode::ContentHashMismatch
+    );
+    
+    capsule.content = content;
+    capsule.sealed = false;
+    capsule.updated_at = clock.unix_timestamp;
+    capsule.last_modified_by = ctx.accounts.owner.key();
+    
+    emit!(ContentRevealed {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        content_hash: capsule.content_hash,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule content revealed: {}", capsule.key());
+    
+    Ok(())
+}
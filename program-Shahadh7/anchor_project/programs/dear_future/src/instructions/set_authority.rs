@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::AuthorityTransferred};
+
+#[derive(Accounts)]
+pub struct SetAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetAuthority>, new_authority: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    require!(
+        new_authority != Pubkey::default() && new_authority != config.authority,
+        ErrorCode::InvalidNewAuthority
+    );
+    
+    let old_authority = config.authority;
+    config.authority = new_authority;
+    
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Config authority transferred from {} to {}", old_authority, new_authority);
+    
+    Ok(())
+}
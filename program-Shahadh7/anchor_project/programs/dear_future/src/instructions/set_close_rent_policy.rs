@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::CloseRentPolicyChanged};
+
+#[derive(Accounts)]
+pub struct SetCloseRentPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetCloseRentPolicy>, rent_to_creator_on_close: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.rent_to_creator_on_close = rent_to_creator_on_close;
+    
+    emit!(CloseRentPolicyChanged {
+        authority: ctx.accounts.authority.key(),
+        rent_to_creator_on_close,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Rent refunded to creator on close: {}", rent_to_creator_on_close);
+    
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::DelegateChanged};
+
+#[derive(Accounts)]
+pub struct SetDelegate<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetDelegate>, delegate: Option<Pubkey>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    let old_delegate = capsule.delegate;
+    capsule.delegate = delegate;
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(DelegateChanged {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        old_delegate,
+        new_delegate: delegate,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule {} delegate set to {:?}", capsule.key(), delegate);
+    
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::ExpiryChanged};
+
+#[derive(Accounts)]
+pub struct SetExpiry<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetExpiry>, expires_at: Option<i64>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    // Expiring before the unlock date would destroy the letter before anyone could read it
+    if let Some(expires_at) = expires_at {
+        require!(
+            expires_at > clock.unix_timestamp && expires_at > capsule.unlock_date,
+            ErrorCode::InvalidExpiry
+        );
+    }
+    
+    capsule.expires_at = expires_at;
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(ExpiryChanged {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        expires_at,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule {} expiry set to {:?}", capsule.key(), expires_at);
+    
+    Ok(())
+}
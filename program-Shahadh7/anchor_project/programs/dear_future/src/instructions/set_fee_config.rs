@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::FeeConfigUpdated};
+
+#[derive(Accounts)]
+pub struct SetFeeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetFeeConfig>,
+    transfer_fee_lamports: u64,
+    create_fee_lamports: u64,
+    treasury: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.transfer_fee_lamports = transfer_fee_lamports;
+    config.create_fee_lamports = create_fee_lamports;
+    config.treasury = treasury;
+    
+    emit!(FeeConfigUpdated {
+        authority: ctx.accounts.authority.key(),
+        transfer_fee_lamports,
+        create_fee_lamports,
+        treasury,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!(
+        "Fee config updated: {} lamports per transfer, {} lamports per creation to treasury {}",
+        transfer_fee_lamports,
+        create_fee_lamports,
+        treasury
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::LimitsUpdated};
+
+#[derive(Accounts)]
+pub struct SetLimits<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetLimits>,
+    max_title: u16,
+    max_content: u16,
+    max_url: u16,
+) -> Result<()> {
+    // Capsule accounts only reserve space up to the hard ceilings
+    require!(
+        max_title > 0 && (max_title as usize) <= HARD_MAX_TITLE_LENGTH,
+        ErrorCode::InvalidLimit
+    );
+    require!(
+        (max_content as usize) >= MIN_CONTENT_LENGTH && (max_content as usize) <= HARD_MAX_CONTENT_LENGTH,
+        ErrorCode::InvalidLimit
+    );
+    require!(
+        max_url > 0 && (max_url as usize) <= HARD_MAX_URL_LENGTH,
+        ErrorCode::InvalidLimit
+    );
+    
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.max_title = max_title;
+    config.max_content = max_content;
+    config.max_url = max_url;
+    
+    emit!(LimitsUpdated {
+        authority: ctx.accounts.authority.key(),
+        max_title,
+        max_content,
+        max_url,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Limits set to title {}, content {}, url {}", max_title, max_content, max_url);
+    
+    Ok(())
+}
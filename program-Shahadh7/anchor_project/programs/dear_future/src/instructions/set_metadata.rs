@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::{state::*, errors::ErrorCode, events::MetadataSet, utils::{validate_text, validate_url}};
+
+#[derive(Accounts)]
+pub struct SetMetadata<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+        constraint = capsule.is_owned_by(&owner.key()) @ ErrorCode::NotOwner,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub owner: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetMetadata>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    // Metadata is frozen once an NFT mint is attached
+    require!(capsule.mint.is_none(), ErrorCode::CapsuleAlreadyHasMint);
+    
+    if let Some(symbol) = &symbol {
+        require!(
+            symbol.len() <= MAX_SYMBOL_LENGTH,
+            ErrorCode::SymbolTooLong
+        );
+        validate_text(symbol)?;
+    }
+    
+    if let Some(uri) = &uri {
+        require!(
+            uri.len() <= MAX_URI_LENGTH,
+            ErrorCode::UriTooLong
+        );
+        validate_url(uri)?;
+    }
+    
+    capsule.symbol = symbol.clone();
+    capsule.uri = uri.clone();
+    capsule.updated_at = clock.unix_timestamp;
+    
+    emit!(MetadataSet {
+        capsule: capsule.key(),
+        owner: ctx.accounts.owner.key(),
+        symbol,
+        uri,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Capsule {} metadata updated", capsule.key());
+    
+    Ok(())
+}
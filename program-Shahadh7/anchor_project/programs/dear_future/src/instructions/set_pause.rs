@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::ProgramPauseChanged};
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetPause>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.paused = paused;
+    
+    emit!(ProgramPauseChanged {
+        authority: ctx.accounts.authority.key(),
+        paused,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Program paused: {}", paused);
+    
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::RateLimitUpdated};
+
+#[derive(Accounts)]
+pub struct SetRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SetRateLimit>,
+    creation_window_seconds: i64,
+    max_per_window: u32,
+) -> Result<()> {
+    require!(creation_window_seconds > 0, ErrorCode::InvalidRateLimitWindow);
+    
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.creation_window_seconds = creation_window_seconds;
+    config.max_per_window = max_per_window;
+    
+    emit!(RateLimitUpdated {
+        authority: ctx.accounts.authority.key(),
+        creation_window_seconds,
+        max_per_window,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Rate limit set to {} capsules per {} seconds", max_per_window, creation_window_seconds);
+    
+    Ok(())
+}
\ No newline at end of file
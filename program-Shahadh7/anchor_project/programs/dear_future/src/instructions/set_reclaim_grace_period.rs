@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::ReclaimGracePeriodChanged};
+
+#[derive(Accounts)]
+pub struct SetReclaimGracePeriod<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetReclaimGracePeriod>, reclaim_grace_period: i64) -> Result<()> {
+    require!(reclaim_grace_period >= 0, ErrorCode::InvalidGracePeriod);
+    
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.reclaim_grace_period = reclaim_grace_period;
+    
+    emit!(ReclaimGracePeriodChanged {
+        authority: ctx.accounts.authority.key(),
+        reclaim_grace_period,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Reclaim grace period set to {} seconds", reclaim_grace_period);
+    
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::{state::Config, errors::ErrorCode, events::TransferPolicyChanged};
+
+#[derive(Accounts)]
+pub struct SetTransferPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetTransferPolicy>, allow_locked_transfer: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.allow_locked_transfer = allow_locked_transfer;
+    
+    emit!(TransferPolicyChanged {
+        authority: ctx.accounts.authority.key(),
+        allow_locked_transfer,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Locked capsule transfers allowed: {}", allow_locked_transfer);
+    
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Config, MAX_UNLOCK_GRACE_SECONDS}, errors::ErrorCode, events::UnlockGraceChanged};
+
+#[derive(Accounts)]
+pub struct SetUnlockGrace<'info> {
+    #[account(
+        mut,
+        seeds = [Config::SEED],
+        bump,
+        constraint = config.authority == authority.key() @ ErrorCode::UnauthorizedAuthority,
+    )]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SetUnlockGrace>, unlock_grace_seconds: i64) -> Result<()> {
+    require!(
+        (0..=MAX_UNLOCK_GRACE_SECONDS).contains(&unlock_grace_seconds),
+        ErrorCode::InvalidUnlockGrace
+    );
+    
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    
+    config.unlock_grace_seconds = unlock_grace_seconds;
+    
+    emit!(UnlockGraceChanged {
+        authority: ctx.accounts.authority.key(),
+        unlock_grace_seconds,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    msg!("Unlock grace set to {} seconds", unlock_grace_seconds);
+    
+    Ok(())
+}
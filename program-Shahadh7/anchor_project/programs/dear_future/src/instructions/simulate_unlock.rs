@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, Config}, events::UnlockCountdown};
+
+#[derive(Accounts)]
+pub struct SimulateUnlock<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub caller: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SimulateUnlock>) -> Result<()> {
+    let capsule = &ctx.accounts.capsule;
+    let caller = ctx.accounts.caller.key();
+    let clock = Clock::get()?;
+    let unlock_grace = ctx.accounts.config.unlock_grace_seconds;
+    
+    emit!(UnlockCountdown {
+        capsule: capsule.key(),
+        caller,
+        seconds_remaining: capsule.effective_unlock_date(unlock_grace).saturating_sub(clock.unix_timestamp),
+        ready: capsule.is_ready_to_unlock(clock.unix_timestamp, unlock_grace),
+        authorized: capsule.can_be_unlocked_by(&caller),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, Subscription}, errors::ErrorCode, events::Subscribed};
+
+#[derive(Accounts)]
+pub struct Subscribe<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [Subscription::SEED, subscriber.key().as_ref(), capsule.key().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Subscribe>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    // Reminders only make sense while there is still an unlock to wait for
+    require!(!capsule.is_unlocked, ErrorCode::CapsuleAlreadyUnlocked);
+    
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.subscriber = ctx.accounts.subscriber.key();
+    subscription.capsule = capsule.key();
+    subscription.created_at = clock.unix_timestamp;
+    subscription.bump = ctx.bumps.subscription;
+    
+    capsule.subscriber_count = capsule.subscriber_count.saturating_add(1);
+    
+    emit!(Subscribed {
+        capsule: capsule.key(),
+        subscriber: ctx.accounts.subscriber.key(),
+        unlock_date: capsule.unlock_date,
+        subscriber_count: capsule.subscriber_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
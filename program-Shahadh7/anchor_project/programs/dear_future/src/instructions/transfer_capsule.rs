@@ -1,8 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token;
 use crate::{state::*, errors::ErrorCode, events::CapsuleTransferred};
 
 #[derive(Accounts)]
 pub struct TransferCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
@@ -17,17 +25,30 @@ pub struct TransferCapsule<'info> {
     /// CHECK: New owner can be any valid public key
     pub new_owner: UncheckedAccount<'info>,
     
+    /// CHECK: Only receives lamports; address is validated against the config
+    #[account(
+        mut,
+        address = config.treasury @ ErrorCode::InvalidTreasury,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+    
+    /// CHECK: Optional SPL mint to associate with the capsule; must be owned by the token program
+    #[account(owner = token::ID @ ErrorCode::InvalidTokenAccount)]
+    pub mint: Option<UncheckedAccount<'info>>,
+    
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<TransferCapsule>,
-    mint_address: Option<Pubkey>,
 ) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
     let clock = Clock::get()?;
     let capsule = &mut ctx.accounts.capsule;
     let new_owner_key = ctx.accounts.new_owner.key();
     let current_owner_key = ctx.accounts.current_owner.key();
+    let mint_address = ctx.accounts.mint.as_ref().map(|mint| mint.key());
     
     // Verify that the current owner is actually the owner
     require!(
@@ -41,20 +62,54 @@ pub fn handler(
         ErrorCode::CannotTransferToSelf
     );
     
-    // If mint address is provided, store it along with the creator's pubkey
+    // Plain capsules stay put until unlocked unless the config allows it; NFT-wrapped ones trade freely
+    require!(
+        ctx.accounts.config.allow_locked_transfer || capsule.is_unlocked || capsule.mint.is_some(),
+        ErrorCode::CannotTransferLockedCapsule
+    );
+    
+    // Collect the transfer fee before ownership changes hands
+    let fee = ctx.accounts.config.transfer_fee_lamports;
+    if fee > 0 {
+        require!(
+            ctx.accounts.current_owner.lamports() >= fee,
+            ErrorCode::InsufficientFeeBalance
+        );
+        
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.current_owner.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+    
+    // If a mint is provided, store it along with the creator's pubkey; an existing mint cannot be replaced
     if let Some(mint) = mint_address {
-        capsule.set_mint_info(mint, current_owner_key, clock.unix_timestamp);
+        match capsule.mint {
+            Some(existing) => require_keys_eq!(existing, mint, ErrorCode::CapsuleAlreadyHasMint),
+            None => {
+                require!(capsule.capsule_type != CapsuleType::Text, ErrorCode::CannotMintTextCapsule);
+                capsule.set_mint_info(mint, current_owner_key, clock.unix_timestamp);
+            }
+        }
     }
     
     // Transfer the capsule
-    capsule.transfer_to(new_owner_key, clock.unix_timestamp);
+    let delegate_cleared = capsule.transfer_to(new_owner_key, clock.unix_timestamp);
     
     emit!(CapsuleTransferred {
         capsule: capsule.key(),
         from: current_owner_key,
         to: new_owner_key,
         mint: mint_address,
+        fee_paid: fee,
         timestamp: clock.unix_timestamp,
+        delegate_cleared,
     });
     
     msg!("Capsule transferred from {} to {}", current_owner_key, new_owner_key);
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, CapsuleReaction}, events::CapsuleUnliked};
+
+#[derive(Accounts)]
+pub struct UnlikeCapsule<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(
+        mut,
+        has_one = reactor,
+        has_one = capsule,
+        close = reactor,
+        seeds = [CapsuleReaction::SEED, reactor.key().as_ref(), capsule.key().as_ref()],
+        bump = capsule_reaction.bump,
+    )]
+    pub capsule_reaction: Account<'info, CapsuleReaction>,
+    
+    #[account(mut)]
+    pub reactor: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UnlikeCapsule>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    capsule.like_count = capsule.like_count.saturating_sub(1);
+    
+    emit!(CapsuleUnliked {
+        capsule: capsule.key(),
+        reactor: ctx.accounts.reactor.key(),
+        like_count: capsule.like_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
\ No newline at end of file
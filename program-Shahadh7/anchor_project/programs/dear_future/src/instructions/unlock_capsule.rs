@@ -1,25 +1,32 @@
 use anchor_lang::prelude::*;
-use crate::{state::Capsule, errors::ErrorCode, events::CapsuleUnlocked};
+use crate::{state::{Capsule, Config}, errors::ErrorCode, events::CapsuleUnlocked};
 
 #[derive(Accounts)]
 pub struct UnlockCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
         bump = capsule.bump,
-        constraint = capsule.owner == owner.key() @ ErrorCode::NotOwner,
+        constraint = capsule.can_be_unlocked_by(&unlocker.key()) @ ErrorCode::NotOwnerOrRecipient,
     )]
     pub capsule: Account<'info, Capsule>,
     
-    pub owner: Signer<'info>,
+    pub unlocker: Signer<'info>,
 }
 
 pub fn handler(ctx: Context<UnlockCapsule>) -> Result<()> {
+    let unlock_grace = ctx.accounts.config.unlock_grace_seconds;
     let capsule = &mut ctx.accounts.capsule;
     let clock = Clock::get()?;
     
     require!(
-        capsule.is_ready_to_unlock(clock.unix_timestamp),
+        capsule.is_ready_to_unlock(clock.unix_timestamp, unlock_grace),
         ErrorCode::CapsuleNotReadyToUnlock
     );
     
@@ -28,11 +35,16 @@ pub fn handler(ctx: Context<UnlockCapsule>) -> Result<()> {
     
     emit!(CapsuleUnlocked {
         capsule: capsule.key(),
-        unlocker: ctx.accounts.owner.key(),
+        unlocker: ctx.accounts.unlocker.key(),
+        recipient: capsule.recipient,
+        creator: capsule.creator,
+        owner: capsule.owner,
+        title: capsule.title.clone(),
+        effective_unlock_date: capsule.effective_unlock_date(unlock_grace),
         timestamp: clock.unix_timestamp,
     });
     
     msg!("Capsule unlocked: {}", capsule.key());
     
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::{state::{Capsule, Subscription}, events::Unsubscribed};
+
+#[derive(Accounts)]
+pub struct Unsubscribe<'info> {
+    #[account(
+        mut,
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    #[account(
+        mut,
+        has_one = subscriber,
+        has_one = capsule,
+        close = subscriber,
+        seeds = [Subscription::SEED, subscriber.key().as_ref(), capsule.key().as_ref()],
+        bump = subscription.bump,
+    )]
+    pub subscription: Account<'info, Subscription>,
+    
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<Unsubscribe>) -> Result<()> {
+    let capsule = &mut ctx.accounts.capsule;
+    let clock = Clock::get()?;
+    
+    capsule.subscriber_count = capsule.subscriber_count.saturating_sub(1);
+    
+    emit!(Unsubscribed {
+        capsule: capsule.key(),
+        subscriber: ctx.accounts.subscriber.key(),
+        subscriber_count: capsule.subscriber_count,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
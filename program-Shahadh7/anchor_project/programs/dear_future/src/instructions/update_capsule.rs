@@ -1,75 +1,230 @@
 use anchor_lang::prelude::*;
-use crate::{state::*, errors::ErrorCode, events::CapsuleUpdated};
+use crate::{state::*, errors::ErrorCode, events::{CapsuleUpdated, CHANGE_CONTENT, CHANGE_DATE, CHANGE_URL, CHANGE_TITLE}, utils::{validate_text, validate_url}};
 
 #[derive(Accounts)]
 pub struct UpdateCapsule<'info> {
+    #[account(
+        seeds = [Config::SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    
     #[account(
         mut,
         seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
         bump = capsule.bump,
-        constraint = capsule.owner == owner.key() @ ErrorCode::NotOwner,
+        constraint = capsule.can_be_updated_by(&updater.key()) @ ErrorCode::NotOwnerOrDelegate,
     )]
     pub capsule: Account<'info, Capsule>,
     
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub updater: Signer<'info>,
+    
+    /// CHECK: Only compared against capsule.mint; required to move the unlock date of a minted capsule
+    pub mint: Option<UncheckedAccount<'info>>,
+}
+
+/// Requested edits; every `None` or empty field leaves that part of the capsule untouched
+pub struct UpdateCapsuleArgs {
+    pub new_title: Option<String>,
+    pub new_content: Option<String>,
+    pub new_unlock_date: Option<i64>,
+    pub add_encrypted_urls: Vec<String>,
+    pub remove_url_indices: Vec<u8>,
+    pub allow_early: bool,
+    pub new_category: Option<CapsuleCategory>,
+    pub new_is_public: Option<bool>,
+    pub new_content_cid: Option<String>,
 }
 
-pub fn handler(
-    ctx: Context<UpdateCapsule>,
-    new_content: Option<String>,
-    new_unlock_date: Option<i64>,
-    new_encrypted_url: Option<String>,
-    remove_encrypted_url: bool,
-) -> Result<()> {
+pub fn handler(ctx: Context<UpdateCapsule>, args: UpdateCapsuleArgs) -> Result<()> {
+    require!(!ctx.accounts.config.paused, ErrorCode::ProgramPaused);
+    
+    let UpdateCapsuleArgs {
+        new_title,
+        new_content,
+        new_unlock_date,
+        add_encrypted_urls,
+        remove_url_indices,
+        allow_early,
+        new_category,
+        new_is_public,
+        new_content_cid,
+    } = args;
+    
     let capsule = &mut ctx.accounts.capsule;
     require!(capsule.can_be_updated(), ErrorCode::CapsuleAlreadyUnlocked);
+    require!(!capsule.archived, ErrorCode::ArchivedCapsuleImmutable);
+    
+    // A delegate may only touch title, content and URLs
+    let is_owner = capsule.is_owned_by(&ctx.accounts.updater.key());
+    require!(
+        is_owner || (new_unlock_date.is_none() && new_category.is_none() && new_is_public.is_none()),
+        ErrorCode::DelegateNotPermitted
+    );
     
     let clock = Clock::get()?;
+    let mut title_updated = false;
     let mut content_updated = false;
-    let mut url_updated = false;
+    let mut added_url_indices: Vec<u8> = Vec::new();
+    
+    // Update title if provided
+    if let Some(title) = new_title {
+        require!(!capsule.locked_hash, ErrorCode::ContentIsLocked);
+        require!(
+            title.len() <= ctx.accounts.config.title_limit(),
+            ErrorCode::TitleTooLong
+        );
+        validate_text(&title)?;
+        capsule.title = title;
+        title_updated = true;
+    }
     
     // Update content if provided
     if let Some(content) = new_content {
+        require!(!capsule.locked_hash, ErrorCode::ContentIsLocked);
         require!(
-            content.len() <= MAX_CONTENT_LENGTH,
+            content.len() <= ctx.accounts.config.content_limit(),
             ErrorCode::ContentTooLong
         );
+        validate_text(&content)?;
         capsule.content = content;
         content_updated = true;
     }
     
-    // Update unlock date if provided
+    // Update the off-chain content pointer if provided; an empty CID clears it
+    if let Some(cid) = new_content_cid {
+        require!(!capsule.locked_hash, ErrorCode::ContentIsLocked);
+        require!(
+            cid.len() <= MAX_CID_LENGTH,
+            ErrorCode::ContentCidTooLong
+        );
+        capsule.content_cid = if cid.is_empty() { None } else { Some(cid) };
+        content_updated = true;
+    }
+    
+    if content_updated {
+        require!(
+            !capsule.content.is_empty() || capsule.content_cid.is_some(),
+            ErrorCode::NoContentProvided
+        );
+        require!(
+            capsule.content_cid.is_some() || capsule.content.len() >= MIN_CONTENT_LENGTH,
+            ErrorCode::ContentTooShort
+        );
+    }
+    
+    // Update unlock date if provided; extend-only unless the owner opts into an earlier date
     if let Some(unlock_date) = new_unlock_date {
+        // Tokenized capsules must name their mint so edits are made against current state
+        if let Some(capsule_mint) = capsule.mint {
+            let mint = ctx.accounts.mint.as_ref().ok_or(ErrorCode::MintRequiredForUpdate)?;
+            require_keys_eq!(mint.key(), capsule_mint, ErrorCode::InvalidTokenAccount);
+        }
+        if allow_early {
+            require!(
+                unlock_date > clock.unix_timestamp,
+                ErrorCode::UnlockDateMustBeFuture
+            );
+        } else {
+            require!(
+                unlock_date > capsule.unlock_date,
+                ErrorCode::InvalidUnlockDateExtension
+            );
+        }
         require!(
-            unlock_date > capsule.unlock_date,
-            ErrorCode::InvalidUnlockDateExtension
+            unlock_date <= clock.unix_timestamp.saturating_add(MAX_UNLOCK_HORIZON),
+            ErrorCode::UnlockDateTooFar
         );
+        if let Some(relock_date) = capsule.relock_date {
+            require!(relock_date > unlock_date, ErrorCode::InvalidRelockDate);
+        }
+        // The capsule must still unlock before it can be reaped
+        if let Some(expires_at) = capsule.expires_at {
+            require!(unlock_date < expires_at, ErrorCode::InvalidExpiry);
+        }
         capsule.unlock_date = unlock_date;
+        capsule.ready_announced = false;
+    }
+    
+    // Update category if provided; not covered by the content hash
+    if let Some(category) = new_category {
+        capsule.category = category;
+    }
+    
+    // Update visibility if provided; only reachable before unlock
+    if let Some(is_public) = new_is_public {
+        capsule.is_public = is_public;
+    }
+    
+    // Remove URLs by index first; indices refer to the list before this update
+    let mut removed_url_indices = remove_url_indices;
+    removed_url_indices.sort_unstable();
+    removed_url_indices.dedup();
+    
+    if let Some(&max_index) = removed_url_indices.last() {
+        require!(
+            (max_index as usize) < capsule.encrypted_urls.len(),
+            ErrorCode::InvalidUrlIndex
+        );
+    }
+    
+    // Remove from the back so earlier indices stay valid
+    for &index in removed_url_indices.iter().rev() {
+        capsule.encrypted_urls.remove(index as usize);
     }
     
-    // Handle encrypted URL updates
-    if remove_encrypted_url {
-        capsule.encrypted_url = None;
-        url_updated = true;
-    } else if let Some(encrypted_url) = new_encrypted_url {
+    // Then append any new URLs; a sealed capsule keeps everything hidden until it is revealed
+    if !add_encrypted_urls.is_empty() {
+        require!(!capsule.sealed, ErrorCode::SealedContentProvided);
+    }
+    require!(
+        capsule.encrypted_urls.len() + add_encrypted_urls.len() <= MAX_URLS,
+        ErrorCode::TooManyUrls
+    );
+    
+    for encrypted_url in add_encrypted_urls {
         require!(
-            encrypted_url.len() <= MAX_URL_LENGTH,
+            encrypted_url.len() <= ctx.accounts.config.url_limit(),
             ErrorCode::UrlTooLong
         );
-        capsule.encrypted_url = Some(encrypted_url);
-        url_updated = true;
+        validate_url(&encrypted_url)?;
+        added_url_indices.push(capsule.encrypted_urls.len() as u8);
+        capsule.encrypted_urls.push(encrypted_url);
+    }
+    
+    let url_updated = !removed_url_indices.is_empty() || !added_url_indices.is_empty();
+    
+    let mut change_mask = 0;
+    if content_updated {
+        change_mask |= CHANGE_CONTENT;
+    }
+    if new_unlock_date.is_some() {
+        change_mask |= CHANGE_DATE;
+    }
+    if url_updated {
+        change_mask |= CHANGE_URL;
+    }
+    if title_updated {
+        change_mask |= CHANGE_TITLE;
     }
     
     capsule.updated_at = clock.unix_timestamp;
+    capsule.last_modified_by = ctx.accounts.updater.key();
     
     emit!(CapsuleUpdated {
         capsule: capsule.key(),
-        updater: ctx.accounts.owner.key(),
+        updater: ctx.accounts.updater.key(),
         new_unlock_date,
+        title_updated,
         content_updated,
         url_updated,
+        new_category,
+        new_is_public,
+        removed_url_indices,
+        added_url_indices,
         timestamp: clock.unix_timestamp,
+        change_mask,
     });
     
     msg!("Capsule updated: {}", capsule.key());
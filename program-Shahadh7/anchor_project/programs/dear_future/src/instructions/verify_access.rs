@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::{state::Capsule, errors::ErrorCode, events::AccessGranted};
+
+#[derive(Accounts)]
+pub struct VerifyAccess<'info> {
+    #[account(
+        seeds = [Capsule::SEED, capsule.creator.as_ref(), &capsule.id.to_le_bytes()],
+        bump = capsule.bump,
+    )]
+    pub capsule: Account<'info, Capsule>,
+    
+    pub requester: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<VerifyAccess>, passphrase: String) -> Result<()> {
+    let capsule = &ctx.accounts.capsule;
+    
+    // Capsules without an access hash are not gated
+    if let Some(access_hash) = capsule.access_hash {
+        require!(
+            Capsule::compute_access_hash(&passphrase) == access_hash,
+            ErrorCode::InvalidAccessPassphrase
+        );
+    }
+    
+    emit!(AccessGranted {
+        capsule: capsule.key(),
+        requester: ctx.accounts.requester.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
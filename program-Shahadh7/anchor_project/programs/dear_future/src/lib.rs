@@ -7,6 +7,7 @@ pub mod instructions;
 pub mod state;
 pub mod errors;
 pub mod events;
+pub mod utils;
 
 declare_id!("88fRjJ3XvAHH1N6468YQd6xuSiPXUX2kyTeD4d3Yz8ng");
 
@@ -20,25 +21,108 @@ pub mod dear_future {
     }
 
     // Create a new memory capsule
+    // Instruction arguments stay positional so the IDL and existing clients are unchanged
+    #[allow(clippy::too_many_arguments)]
     pub fn create_capsule(
         ctx: Context<CreateCapsule>,
         title: String,
         content: String,
         unlock_date: i64,
-        encrypted_url: Option<String>,
+        encrypted_urls: Vec<String>,
+        recipient: Option<Pubkey>,
+        locked_hash: bool,
+        category: state::CapsuleCategory,
+        is_public: bool,
+        relock_date: Option<i64>,
+        content_cid: Option<String>,
+        access_hash: Option<[u8; 32]>,
+        sealed_content_hash: Option<[u8; 32]>,
+        capsule_type: state::CapsuleType,
     ) -> Result<()> {
-        instructions::create_capsule::handler(ctx, title, content, unlock_date, encrypted_url)
+        instructions::create_capsule::handler(ctx, instructions::CreateCapsuleArgs {
+            title,
+            content,
+            unlock_date,
+            encrypted_urls,
+            recipient,
+            locked_hash,
+            category,
+            is_public,
+            relock_date,
+            content_cid,
+            access_hash,
+            sealed_content_hash,
+            capsule_type,
+        })
+    }
+
+    // Create a capsule owned by the recipient from the start
+    pub fn create_gift(
+        ctx: Context<CreateCapsule>,
+        recipient: Pubkey,
+        title: String,
+        content: String,
+        unlock_date: i64,
+        encrypted_urls: Vec<String>,
+        capsule_type: state::CapsuleType,
+    ) -> Result<()> {
+        instructions::create_gift::handler(ctx, recipient, title, content, unlock_date, encrypted_urls, capsule_type)
+    }
+
+    // Create a capsule co-signed by witnesses; witness keys are passed as signing remaining accounts
+    pub fn create_capsule_witnessed<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateCapsule<'info>>,
+        title: String,
+        content: String,
+        unlock_date: i64,
+        encrypted_urls: Vec<String>,
+        capsule_type: state::CapsuleType,
+    ) -> Result<()> {
+        instructions::create_capsule_witnessed::handler(ctx, title, content, unlock_date, encrypted_urls, capsule_type)
+    }
+
+    // Create several memory capsules at once; capsule PDAs are passed as remaining accounts
+    pub fn create_capsules_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateCapsulesBatch<'info>>,
+        capsules: Vec<CapsuleInput>,
+    ) -> Result<()> {
+        instructions::create_capsules_batch::handler(ctx, capsules)
     }
 
     // Update a memory capsule
+    // Positional for the same reason as create_capsule
+    #[allow(clippy::too_many_arguments)]
     pub fn update_capsule(
         ctx: Context<UpdateCapsule>,
+        new_title: Option<String>,
         new_content: Option<String>,
         new_unlock_date: Option<i64>,
-        new_encrypted_url: Option<String>,
-        remove_encrypted_url: bool,
+        add_encrypted_urls: Vec<String>,
+        remove_url_indices: Vec<u8>,
+        allow_early: bool,
+        new_category: Option<state::CapsuleCategory>,
+        new_is_public: Option<bool>,
+        new_content_cid: Option<String>,
     ) -> Result<()> {
-        instructions::update_capsule::handler(ctx, new_content, new_unlock_date, new_encrypted_url, remove_encrypted_url)
+        instructions::update_capsule::handler(ctx, instructions::UpdateCapsuleArgs {
+            new_title,
+            new_content,
+            new_unlock_date,
+            add_encrypted_urls,
+            remove_url_indices,
+            allow_early,
+            new_category,
+            new_is_public,
+            new_content_cid,
+        })
+    }
+
+    // Add text to the end of a still-locked capsule's content instead of replacing it
+    pub fn append_content(
+        ctx: Context<AppendContent>,
+        extra: String,
+    ) -> Result<()> {
+        instructions::append_content::handler(ctx, extra)
     }
 
     // Unlock a memory capsule
@@ -48,6 +132,148 @@ pub mod dear_future {
         instructions::unlock_capsule::handler(ctx)
     }
 
+    // Permissionless: announce once that a capsule's unlock date has passed
+    pub fn mark_ready(
+        ctx: Context<MarkReady>,
+    ) -> Result<()> {
+        instructions::mark_ready::handler(ctx)
+    }
+
+    // Write the content of a sealed capsule after unlock; it must match the hash committed at creation (owner only)
+    pub fn reveal_content(
+        ctx: Context<RevealContent>,
+        content: String,
+    ) -> Result<()> {
+        instructions::reveal_content::handler(ctx, content)
+    }
+
+    // Register a view on an unlocked public capsule
+    pub fn register_view(
+        ctx: Context<RegisterView>,
+    ) -> Result<()> {
+        instructions::register_view::handler(ctx)
+    }
+
+    // Like an unlocked public capsule; one reaction account per reactor
+    pub fn like_capsule(
+        ctx: Context<LikeCapsule>,
+    ) -> Result<()> {
+        instructions::like_capsule::handler(ctx)
+    }
+
+    // Remove a like and refund the reaction account rent
+    pub fn unlike_capsule(
+        ctx: Context<UnlikeCapsule>,
+    ) -> Result<()> {
+        instructions::unlike_capsule::handler(ctx)
+    }
+
+    // Opt into unlock reminders for a still-locked capsule; one subscription account per subscriber
+    pub fn subscribe(
+        ctx: Context<Subscribe>,
+    ) -> Result<()> {
+        instructions::subscribe::handler(ctx)
+    }
+
+    // Stop unlock reminders and refund the subscription account rent
+    pub fn unsubscribe(
+        ctx: Context<Unsubscribe>,
+    ) -> Result<()> {
+        instructions::unsubscribe::handler(ctx)
+    }
+
+    // Check a share-link passphrase against the capsule's access hash; a soft gate, not encryption
+    pub fn verify_access(
+        ctx: Context<VerifyAccess>,
+        passphrase: String,
+    ) -> Result<()> {
+        instructions::verify_access::handler(ctx, passphrase)
+    }
+
+    // Read-only: derive the capsule status (locked, ready, unlocked or archived) from its flags and the clock
+    pub fn get_capsule_status(
+        ctx: Context<GetCapsuleStatus>,
+    ) -> Result<state::CapsuleStatus> {
+        instructions::get_capsule_status::handler(ctx)
+    }
+
+    // Read-only: emit the on-chain time left until the capsule unlocks and whether the caller may unlock it
+    pub fn simulate_unlock(
+        ctx: Context<SimulateUnlock>,
+    ) -> Result<()> {
+        instructions::simulate_unlock::handler(ctx)
+    }
+
+    // Read-only: emit the config authority, capsule count and version as an event for subscribers
+    pub fn get_config(
+        ctx: Context<GetConfig>,
+    ) -> Result<()> {
+        instructions::get_config::handler(ctx)
+    }
+
+    // Read-only: emit the capsule PDA for a creator and id so clients need not re-implement the seeds
+    pub fn derive_capsule_address(
+        ctx: Context<DeriveCapsuleAddress>,
+        creator: Pubkey,
+        id: u64,
+    ) -> Result<()> {
+        instructions::derive_capsule_address::handler(ctx, creator, id)
+    }
+
+    // Add a co-owner who may also unlock the capsule (primary owner only)
+    pub fn add_co_owner(
+        ctx: Context<AddCoOwner>,
+        co_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::add_co_owner::handler(ctx, co_owner)
+    }
+
+    // Attest to a still-locked capsule; both the owner and the witness sign
+    pub fn add_witness(
+        ctx: Context<AddWitness>,
+    ) -> Result<()> {
+        instructions::add_witness::handler(ctx)
+    }
+
+    // Remove a co-owner from the capsule (primary owner only)
+    pub fn remove_co_owner(
+        ctx: Context<RemoveCoOwner>,
+        co_owner: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_co_owner::handler(ctx, co_owner)
+    }
+
+    // Set or clear the delegate allowed to edit the capsule content (owner only)
+    pub fn set_delegate(
+        ctx: Context<SetDelegate>,
+        delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_delegate::handler(ctx, delegate)
+    }
+
+    // Set or clear the NFT metadata symbol and URI before a mint is attached (owner only)
+    pub fn set_metadata(
+        ctx: Context<SetMetadata>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::set_metadata::handler(ctx, symbol, uri)
+    }
+
+    // Upgrade a capsule written with an older account layout to the config's schema version
+    pub fn migrate_capsule(
+        ctx: Context<MigrateCapsule>,
+    ) -> Result<()> {
+        instructions::migrate_capsule::handler(ctx)
+    }
+
+    // Grow a config still using the original layout to the current one (config authority only)
+    pub fn migrate_config(
+        ctx: Context<MigrateConfig>,
+    ) -> Result<()> {
+        instructions::migrate_config::handler(ctx)
+    }
+
     // Close a memory capsule
     pub fn close_capsule(
         ctx: Context<CloseCapsule>,
@@ -55,11 +281,174 @@ pub mod dear_future {
         instructions::close_capsule::handler(ctx)
     }
 
+    // Close several unlocked capsules at once; capsules are passed as remaining accounts
+    pub fn close_capsules_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseCapsulesBatch<'info>>,
+    ) -> Result<()> {
+        instructions::close_capsules_batch::handler(ctx)
+    }
+
+    // Let a capsule expire; pass None to keep it forever
+    pub fn set_expiry(
+        ctx: Context<SetExpiry>,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::set_expiry::handler(ctx, expires_at)
+    }
+
+    // Permissionless: close an expired capsule and return its rent to the creator
+    pub fn reap_expired(
+        ctx: Context<ReapExpired>,
+    ) -> Result<()> {
+        instructions::reap_expired::handler(ctx)
+    }
+
+    // Close a still-locked memory capsule; `confirm` must be true to guard against accidents
+    pub fn discard_capsule(
+        ctx: Context<DiscardCapsule>,
+        confirm: bool,
+    ) -> Result<()> {
+        instructions::discard_capsule::handler(ctx, confirm)
+    }
+
     // Transfer a memory capsule to a new owner
     pub fn transfer_capsule(
         ctx: Context<TransferCapsule>,
-        mint_address: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::transfer_capsule::handler(ctx, mint_address)
+        instructions::transfer_capsule::handler(ctx)
+    }
+
+    // Propose a new owner; ownership only moves once they call accept_transfer
+    pub fn initiate_transfer(
+        ctx: Context<InitiateTransfer>,
+    ) -> Result<()> {
+        instructions::initiate_transfer::handler(ctx)
+    }
+
+    // Take ownership of a capsule as its pending owner, paying the transfer fee
+    pub fn accept_transfer(
+        ctx: Context<AcceptTransfer>,
+    ) -> Result<()> {
+        instructions::accept_transfer::handler(ctx)
+    }
+
+    // Withdraw a pending transfer (current owner only)
+    pub fn cancel_transfer(
+        ctx: Context<CancelTransfer>,
+    ) -> Result<()> {
+        instructions::cancel_transfer::handler(ctx)
+    }
+
+    // Return a still-locked capsule to its creator once the reclaim grace period after a transfer has passed (creator only)
+    pub fn reclaim_capsule(
+        ctx: Context<ReclaimCapsule>,
+    ) -> Result<()> {
+        instructions::reclaim_capsule::handler(ctx)
+    }
+
+    // Archive a memory capsule, hiding it without deleting its data
+    pub fn archive_capsule(
+        ctx: Context<ArchiveCapsule>,
+    ) -> Result<()> {
+        instructions::archive_capsule::handler(ctx)
+    }
+
+    // Restore an archived memory capsule
+    pub fn unarchive_capsule(
+        ctx: Context<UnarchiveCapsule>,
+    ) -> Result<()> {
+        instructions::unarchive_capsule::handler(ctx)
+    }
+
+    // Pause or resume capsule creation, updates and transfers
+    pub fn set_pause(
+        ctx: Context<SetPause>,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::set_pause::handler(ctx, paused)
+    }
+
+    // Unlock a memory capsule ahead of its unlock date (config authority only)
+    pub fn authority_unlock(
+        ctx: Context<AuthorityUnlock>,
+    ) -> Result<()> {
+        instructions::authority_unlock::handler(ctx)
+    }
+
+    // Set the capsule transfer and creation fees and the treasury receiving them (config authority only)
+    pub fn set_fee_config(
+        ctx: Context<SetFeeConfig>,
+        transfer_fee_lamports: u64,
+        create_fee_lamports: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        instructions::set_fee_config::handler(ctx, transfer_fee_lamports, create_fee_lamports, treasury)
+    }
+
+    // Close the program config and reclaim its rent (config authority only)
+    pub fn close_config(
+        ctx: Context<CloseConfig>,
+        force: bool,
+    ) -> Result<()> {
+        instructions::close_config::handler(ctx, force)
+    }
+
+    // Hand the config authority over to a new key (config authority only)
+    pub fn set_authority(
+        ctx: Context<SetAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::set_authority::handler(ctx, new_authority)
+    }
+
+    // Tune the per-creator capsule creation rate limit (config authority only)
+    pub fn set_rate_limit(
+        ctx: Context<SetRateLimit>,
+        creation_window_seconds: i64,
+        max_per_window: u32,
+    ) -> Result<()> {
+        instructions::set_rate_limit::handler(ctx, creation_window_seconds, max_per_window)
+    }
+
+    // Tune the title, content and URL length limits up to the hard maximums (config authority only)
+    pub fn set_limits(
+        ctx: Context<SetLimits>,
+        max_title: u16,
+        max_content: u16,
+        max_url: u16,
+    ) -> Result<()> {
+        instructions::set_limits::handler(ctx, max_title, max_content, max_url)
+    }
+
+    // Allow or forbid transferring capsules that are still locked (config authority only)
+    pub fn set_transfer_policy(
+        ctx: Context<SetTransferPolicy>,
+        allow_locked_transfer: bool,
+    ) -> Result<()> {
+        instructions::set_transfer_policy::handler(ctx, allow_locked_transfer)
+    }
+
+    // Choose whether closed capsules refund their rent to the creator instead of the owner (config authority only)
+    pub fn set_close_rent_policy(
+        ctx: Context<SetCloseRentPolicy>,
+        rent_to_creator_on_close: bool,
+    ) -> Result<()> {
+        instructions::set_close_rent_policy::handler(ctx, rent_to_creator_on_close)
+    }
+
+    // Set how long after a transfer the creator must wait before reclaiming a capsule (config authority only)
+    pub fn set_reclaim_grace_period(
+        ctx: Context<SetReclaimGracePeriod>,
+        reclaim_grace_period: i64,
+    ) -> Result<()> {
+        instructions::set_reclaim_grace_period::handler(ctx, reclaim_grace_period)
+    }
+
+    // Delay every unlock by a few seconds past its unlock date to absorb clock skew (config authority only)
+    pub fn set_unlock_grace(
+        ctx: Context<SetUnlockGrace>,
+        unlock_grace_seconds: i64,
+    ) -> Result<()> {
+        instructions::set_unlock_grace::handler(ctx, unlock_grace_seconds)
     }
 }
@@ -1,14 +1,68 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::errors::ErrorCode;
 
 /// Global configuration for the Dear Future program
 
 pub const MAX_TITLE_LENGTH: usize = 100;
 pub const MAX_CONTENT_LENGTH: usize = 300;
+pub const MIN_CONTENT_LENGTH: usize = 5; // Waived when the content lives off-chain behind a CID
 pub const MAX_URL_LENGTH: usize = 500;
+pub const HARD_MAX_TITLE_LENGTH: usize = 200; // Space reserved per capsule; set_limits cannot go above these
+pub const HARD_MAX_CONTENT_LENGTH: usize = 1000;
+pub const HARD_MAX_URL_LENGTH: usize = 600;
+pub const MAX_URLS: usize = 10;
+pub const MAX_CID_LENGTH: usize = 100;
+pub const MAX_SYMBOL_LENGTH: usize = 10; // Metaplex metadata limits
+pub const MAX_URI_LENGTH: usize = 200;
+pub const MAX_CO_OWNERS: usize = 5;
+pub const MAX_WITNESSES: usize = 5;
+pub const MAX_BATCH: usize = 5; // Capsules per create_capsules_batch call, bounded by compute budget
+pub const MAX_CLOSE_BATCH: usize = 20; // Capsules per close_capsules_batch call; closing is much cheaper than creating
+pub const CREATOR_INDEX_SIZE: usize = 20; // Most recent capsules kept per creator in CreatorIndex
+pub const MAX_UNLOCK_HORIZON: i64 = 100 * 365 * 24 * 60 * 60; // ~100 years in seconds
+pub const DEFAULT_CREATION_WINDOW: i64 = 24 * 60 * 60; // 1 day in seconds
+pub const DEFAULT_RECLAIM_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days in seconds
+pub const MAX_UNLOCK_GRACE_SECONDS: i64 = 60 * 60; // Upper bound for Config::unlock_grace_seconds
+
+pub const ORIGINAL_SCHEMA_VERSION: u8 = 1; // Config and capsule layout as first deployed
+pub const CURRENT_SCHEMA_VERSION: u8 = 3; // Bump whenever the Capsule or Config layout changes
+
+/// The only key allowed to call `initialize_config`, read from the `DEPLOY_AUTHORITY` environment
+/// variable at build time so a deploy cannot silently ship without an admin wallet.
+/// Builds with the `localnet` feature (used for tests) accept any initializer and may leave it unset.
+pub const DEPLOY_AUTHORITY: Pubkey = match option_env!("DEPLOY_AUTHORITY") {
+    Some(key) => Pubkey::from_str_const(key),
+    None if cfg!(feature = "localnet") => Pubkey::new_from_array([0; 32]),
+    None => panic!("set DEPLOY_AUTHORITY to the admin wallet, or build with the localnet feature"),
+};
 
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
+    pub authority: Pubkey,
+    pub total_capsules: u64,
+    pub version: u8,
+    pub paused: bool,
+    pub transfer_fee_lamports: u64,
+    pub treasury: Pubkey,
+    pub creation_window_seconds: i64,
+    pub max_per_window: u32, // 0 disables the per-creator rate limit
+    pub create_fee_lamports: u64,
+    pub allow_locked_transfer: bool, // When false, only unlocked or minted capsules can be transferred
+    pub max_title: u16,              // 0 (configs created before set_limits) falls back to MAX_TITLE_LENGTH
+    pub max_content: u16,            // 0 falls back to MAX_CONTENT_LENGTH
+    pub max_url: u16,                // 0 falls back to MAX_URL_LENGTH
+    pub reserved: [u8; 3],
+    pub reclaim_grace_period: i64,   // Seconds after a transfer before the creator may reclaim a still-locked capsule
+    pub unlock_grace_seconds: i64,   // Added to every unlock date to absorb validator clock skew
+    pub rent_to_creator_on_close: bool, // When true, closing a capsule refunds its rent to the creator rather than the owner
+}
+
+/// Config as first deployed; `paused` was later carved out of the first reserved byte.
+/// Only read by `migrate_config`, which grows such accounts to the current layout.
+#[derive(AnchorDeserialize)]
+pub struct ConfigV0 {
     pub authority: Pubkey,
     pub total_capsules: u64,
     pub version: u8,
@@ -17,6 +71,193 @@ pub struct Config {
 
 impl Config {
     pub const SEED: &'static [u8] = b"config";
+
+    /// Settings for a fresh config; shared by `initialize_config` and `migrate_config`
+    pub fn with_defaults(authority: Pubkey) -> Self {
+        Self {
+            authority,
+            total_capsules: 0,
+            version: CURRENT_SCHEMA_VERSION,
+            paused: false,
+            transfer_fee_lamports: 0,
+            treasury: authority,
+            creation_window_seconds: DEFAULT_CREATION_WINDOW,
+            max_per_window: 0,
+            create_fee_lamports: 0,
+            allow_locked_transfer: true,
+            max_title: Capsule::MAX_TITLE_LENGTH as u16,
+            max_content: Capsule::MAX_CONTENT_LENGTH as u16,
+            max_url: Capsule::MAX_URL_LENGTH as u16,
+            reserved: [0; 3],
+            reclaim_grace_period: DEFAULT_RECLAIM_GRACE_PERIOD,
+            unlock_grace_seconds: 0,
+            rent_to_creator_on_close: false,
+        }
+    }
+
+    pub fn can_be_initialized_by(authority: &Pubkey) -> bool {
+        cfg!(feature = "localnet") || *authority == DEPLOY_AUTHORITY
+    }
+
+    pub fn title_limit(&self) -> usize {
+        if self.max_title == 0 { Capsule::MAX_TITLE_LENGTH } else { self.max_title as usize }
+    }
+
+    pub fn content_limit(&self) -> usize {
+        if self.max_content == 0 { Capsule::MAX_CONTENT_LENGTH } else { self.max_content as usize }
+    }
+
+    pub fn url_limit(&self) -> usize {
+        if self.max_url == 0 { Capsule::MAX_URL_LENGTH } else { self.max_url as usize }
+    }
+}
+
+/// Per-creator counters used to rate-limit capsule creation and allocate capsule IDs
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorStats {
+    pub creator: Pubkey,
+    pub created_count: u64,
+    pub window_start: i64,
+    pub window_count: u32,
+    pub bump: u8,
+    pub next_id: u64, // Capsule PDA seed for this creator's next capsule; never decremented
+}
+
+impl CreatorStats {
+    pub const SEED: &'static [u8] = b"creator_stats";
+
+    pub fn record_creations(
+        &mut self,
+        count: u32,
+        now: i64,
+        window_seconds: i64,
+        max_per_window: u32,
+    ) -> Result<()> {
+        if now.saturating_sub(self.window_start) >= window_seconds {
+            self.window_start = now;
+            self.window_count = 0;
+        }
+
+        let window_count = self
+            .window_count
+            .checked_add(count)
+            .ok_or(ErrorCode::CreationRateLimited)?;
+        require!(
+            max_per_window == 0 || window_count <= max_per_window,
+            ErrorCode::CreationRateLimited
+        );
+
+        self.window_count = window_count;
+        self.created_count = self.created_count.saturating_add(count as u64);
+        Ok(())
+    }
+
+    /// Returns the ID for the creator's next capsule and advances the sequence
+    pub fn allocate_id(&mut self) -> Result<u64> {
+        let id = self.next_id;
+        self.next_id = id.checked_add(1).ok_or(ErrorCode::CounterOverflow)?;
+        Ok(id)
+    }
+}
+
+/// Ring buffer of a creator's most recently created capsules, so clients can list them without a program account scan
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorIndex {
+    pub creator: Pubkey,
+    #[max_len(CREATOR_INDEX_SIZE)]
+    pub capsules: Vec<Pubkey>,
+    pub head: u8, // Once full, the slot holding the oldest entry and the next one to be overwritten
+    pub bump: u8,
+}
+
+impl CreatorIndex {
+    pub const SEED: &'static [u8] = b"creator_index";
+
+    pub fn record(&mut self, capsule: Pubkey) {
+        if self.capsules.len() < CREATOR_INDEX_SIZE {
+            self.capsules.push(capsule);
+        } else {
+            self.capsules[self.head as usize] = capsule;
+            self.head = ((self.head as usize + 1) % CREATOR_INDEX_SIZE) as u8;
+        }
+    }
+}
+
+/// One like on a public capsule; closing it refunds the reactor
+#[account]
+#[derive(InitSpace)]
+pub struct CapsuleReaction {
+    pub reactor: Pubkey,
+    pub capsule: Pubkey,
+    pub bump: u8,
+}
+
+impl CapsuleReaction {
+    pub const SEED: &'static [u8] = b"capsule_reaction";
+}
+
+/// Opt-in to unlock reminders; indexers watch the capsule's events for each subscriber
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub subscriber: Pubkey,
+    pub capsule: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Subscription {
+    pub const SEED: &'static [u8] = b"subscription";
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CapsuleCategory {
+    Personal,
+    Gift,
+    TimeVault,
+    Other,
+}
+
+/// What a capsule holds; only media and NFT capsules can have a mint attached
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CapsuleType {
+    Text,
+    Media,
+    Nft,
+}
+
+/// Lifecycle status derived on-chain from the capsule flags and the clock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CapsuleStatus {
+    Locked,
+    Ready,
+    Unlocked,
+    Archived,
+}
+
+/// Capsule as first deployed (`ORIGINAL_SCHEMA_VERSION`). Only read by `migrate_capsule`,
+/// since later fields were inserted before `title` and the URL became a list.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct CapsuleV0 {
+    pub creator: Pubkey,
+    pub owner: Pubkey,
+    pub id: u64,
+    pub unlock_date: i64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub transferred_at: Option<i64>,
+    pub mint: Option<Pubkey>,
+    pub mint_creator: Option<Pubkey>,
+    pub bump: u8,
+    pub is_unlocked: bool,
+    #[max_len(100)]
+    pub title: String,
+    #[max_len(300)]
+    pub content: String,
+    #[max_len(500)]
+    pub encrypted_url: Option<String>,
 }
 
 #[account]
@@ -25,31 +266,109 @@ pub struct Capsule {
     // Core fields - packed for efficiency
     pub creator: Pubkey,          // Original creator of the capsule
     pub owner: Pubkey,            // Current owner of the capsule (can be different from creator after transfer)
+    pub recipient: Option<Pubkey>,    // Designated recipient who may also unlock the capsule
     pub id: u64,
     pub unlock_date: i64,
+    pub relock_date: Option<i64>,     // Once reached, an unlocked capsule is treated as locked again
     pub created_at: i64,
     pub updated_at: i64,
     pub transferred_at: Option<i64>,  // When the capsule was last transferred
     pub mint: Option<Pubkey>,         // NFT mint address if minted
     pub mint_creator: Option<Pubkey>, // Creator's public key stored when NFT is minted
+    pub content_hash: [u8; 32],       // SHA-256 of title + content at creation time
+    pub locked_hash: bool,            // When true, title and content can no longer be edited
+    pub category: CapsuleCategory,    // Used by the frontend to group and filter capsules
     pub bump: u8,
     pub is_unlocked: bool,
+    pub archived: bool,               // Hidden from default views; reversible unlike close_capsule
+    pub is_public: bool,              // Readable by anyone once unlocked
+    pub view_count: u64,              // Views registered on an unlocked public capsule
     
     // String fields with max lengths - these are stored on-chain
-    #[max_len(MAX_TITLE_LENGTH)]
+    #[max_len(HARD_MAX_TITLE_LENGTH)]
     pub title: String,
-    #[max_len(MAX_CONTENT_LENGTH)]
+    #[max_len(HARD_MAX_CONTENT_LENGTH)]
     pub content: String,
-    #[max_len(MAX_URL_LENGTH)]
-    pub encrypted_url: Option<String>,
+    #[max_len(MAX_URLS, HARD_MAX_URL_LENGTH)]
+    pub encrypted_urls: Vec<String>,
+    #[max_len(MAX_CO_OWNERS)]
+    pub co_owners: Vec<Pubkey>,       // Additional keys allowed to unlock, managed by the owner
+    pub delegate: Option<Pubkey>,     // May edit title, content and URLs on the owner's behalf
+    pub schema_version: u8,           // Layout version; new fields go after this so migrate_capsule can read older accounts
+    pub like_count: u64,              // Live CapsuleReaction accounts for this capsule
+    #[max_len(MAX_CID_LENGTH)]
+    pub content_cid: Option<String>,  // IPFS/Arweave CID of the full letter; `content` may then be a short preview
+    pub access_hash: Option<[u8; 32]>, // SHA-256 of a share-link passphrase checked by verify_access; not encryption
+    pub last_modified_by: Pubkey,     // Creator until the first update_capsule, then the latest updater (owner or delegate)
+    pub ready_announced: bool,        // Set by mark_ready so CapsuleReady is emitted once per unlock date
+    pub pending_owner: Option<Pubkey>, // Set by initiate_transfer; ownership moves only once this key accepts
+    pub sealed: bool,                 // Content stays empty until reveal_content supplies text matching content_hash
+    #[max_len(MAX_SYMBOL_LENGTH)]
+    pub symbol: Option<String>,       // NFT metadata symbol, editable until a mint is attached
+    #[max_len(MAX_URI_LENGTH)]
+    pub uri: Option<String>,          // NFT metadata JSON URI, editable until a mint is attached
+    pub reserved: [u8; 10],           // Held the dropped unlock attempt counter; keeps later fields in place
+    pub expires_at: Option<i64>,      // Set by set_expiry; from then on anyone may reap_expired the capsule
+    pub capsule_type: CapsuleType,    // Fixed at creation; Text capsules can never be minted
+    #[max_len(MAX_WITNESSES)]
+    pub witnesses: Vec<Pubkey>,       // Keys that co-signed to attest the capsule's authenticity
+    pub subscriber_count: u64,        // Live Subscription accounts for this capsule
+    pub previous_owner: Option<Pubkey>, // Owner before the latest transfer; reclaim requires this to be the creator
+}
+
+/// Address of the capsule a creator gets for the given id, as used by create_capsule
+pub fn derive_capsule_pda(creator: &Pubkey, id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[Capsule::SEED, creator.as_ref(), &id.to_le_bytes()], &crate::ID)
 }
 
 impl Capsule {
     pub const SEED: &'static [u8] = b"capsule";
+    // Default length limits, reachable through the type; Config may lower or raise them up to HARD_MAX_*
+    pub const MAX_TITLE_LENGTH: usize = MAX_TITLE_LENGTH;
+    pub const MAX_CONTENT_LENGTH: usize = MAX_CONTENT_LENGTH;
+    pub const MAX_URL_LENGTH: usize = MAX_URL_LENGTH;
+
+    pub fn compute_content_hash(title: &str, content: &str) -> [u8; 32] {
+        hashv(&[title.as_bytes(), content.as_bytes()]).to_bytes()
+    }
+
+    pub fn compute_access_hash(passphrase: &str) -> [u8; 32] {
+        hashv(&[passphrase.as_bytes()]).to_bytes()
+    }
+
+    // The unlock date pushed back by the config-wide unlock grace
+    #[inline(always)]
+    pub fn effective_unlock_date(&self, unlock_grace: i64) -> i64 {
+        self.unlock_date.saturating_add(unlock_grace)
+    }
+
+    #[inline(always)]
+    pub fn is_ready_to_unlock(&self, current_time: i64, unlock_grace: i64) -> bool {
+        current_time >= self.effective_unlock_date(unlock_grace)
+    }
+
+    #[inline(always)]
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if current_time >= expires_at)
+    }
 
     #[inline(always)]
-    pub fn is_ready_to_unlock(&self, current_time: i64) -> bool {
-        current_time >= self.unlock_date
+    pub fn is_relocked(&self, current_time: i64) -> bool {
+        matches!(self.relock_date, Some(relock_date) if current_time >= relock_date)
+    }
+
+    pub fn status(&self, current_time: i64, unlock_grace: i64) -> CapsuleStatus {
+        if self.archived {
+            CapsuleStatus::Archived
+        } else if self.is_relocked(current_time) {
+            CapsuleStatus::Locked
+        } else if self.is_unlocked {
+            CapsuleStatus::Unlocked
+        } else if self.is_ready_to_unlock(current_time, unlock_grace) {
+            CapsuleStatus::Ready
+        } else {
+            CapsuleStatus::Locked
+        }
     }
 
     #[inline(always)]
@@ -63,17 +382,43 @@ impl Capsule {
         self.owner == *caller
     }
 
+    #[inline(always)]
+    pub fn can_be_unlocked_by(&self, caller: &Pubkey) -> bool {
+        // The owner, a co-owner or the designated recipient can unlock the capsule
+        self.owner == *caller
+            || self.co_owners.contains(caller)
+            || self.recipient == Some(*caller)
+    }
+
+    #[inline(always)]
+    pub fn can_be_updated_by(&self, caller: &Pubkey) -> bool {
+        // The owner or the current delegate can update the capsule
+        self.owner == *caller || self.delegate == Some(*caller)
+    }
+
     #[inline(always)]
     pub fn is_owned_by(&self, pubkey: &Pubkey) -> bool {
         self.owner == *pubkey
     }
 
-    pub fn transfer_to(&mut self, new_owner: Pubkey, timestamp: i64) {
+    // The new owner never inherits the previous owner's delegate; returns whether one was set
+    pub fn transfer_to(&mut self, new_owner: Pubkey, timestamp: i64) -> bool {
+        let delegate_cleared = self.delegate.take().is_some();
+        self.previous_owner = Some(self.owner);
         self.owner = new_owner;
+        self.pending_owner = None;
         self.transferred_at = Some(timestamp);
         self.updated_at = timestamp;
+        delegate_cleared
     }
 
+    pub fn add_witness(&mut self, witness: Pubkey) -> Result<()> {
+        require!(!self.witnesses.contains(&witness), ErrorCode::DuplicateWitness);
+        require!(self.witnesses.len() < MAX_WITNESSES, ErrorCode::TooManyWitnesses);
+        self.witnesses.push(witness);
+        Ok(())
+    }
+    
     pub fn set_mint_info(&mut self, mint: Pubkey, mint_creator: Pubkey, timestamp: i64) {
         self.mint = Some(mint);
         self.mint_creator = Some(mint_creator);
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use crate::errors::ErrorCode;
+
+pub const ALLOWED_URL_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// Rejects URLs without a supported scheme, with nothing after the scheme,
+/// or containing control characters
+pub fn validate_url(url: &str) -> Result<()> {
+    let has_scheme = ALLOWED_URL_SCHEMES
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && url.starts_with(scheme));
+
+    require!(
+        has_scheme && !url.chars().any(char::is_control),
+        ErrorCode::InvalidUrlFormat
+    );
+
+    Ok(())
+}
+
+/// Rejects text containing control characters other than newline and tab
+pub fn validate_text(text: &str) -> Result<()> {
+    require!(
+        !text.chars().any(|c| c.is_control() && c != '\n' && c != '\t'),
+        ErrorCode::InvalidCharacters
+    );
+
+    Ok(())
+}
@@ -2,7 +2,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { DearFuture } from "../target/types/dear_future";
 import { expect } from "chai";
+import { createMint } from "@solana/spl-token";
 import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { createHash } from "crypto";
 
 describe("Dear Future: Capsules Management ", () => {
   const provider = anchor.AnchorProvider.env();
@@ -29,6 +31,16 @@ describe("Dear Future: Capsules Management ", () => {
     }
   }
 
+  // Capsule IDs are allocated per creator from their CreatorStats account
+  async function nextCapsuleId(creator: PublicKey): Promise<anchor.BN> {
+    const [creatorStatsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_stats"), creator.toBuffer()],
+      program.programId
+    );
+    const stats = await program.account.creatorStats.fetchNullable(creatorStatsPda);
+    return stats ? stats.nextId : new anchor.BN(0);
+  }
+
 
   const program = anchor.workspace.DearFuture as Program<DearFuture>;
   const wallet = provider.wallet as anchor.Wallet;
@@ -86,7 +98,7 @@ describe("Dear Future: Capsules Management ", () => {
 
       const configAccount = await program.account.config.fetch(configPda);
       expect(configAccount.authority.toString()).to.equal(wallet.publicKey.toString());
-      expect(configAccount.version).to.equal(1);
+      expect(configAccount.version).to.equal(3);
       if (initAttempted) {
         expect(configAccount.totalCapsules.toNumber()).to.equal(0);
       }
@@ -107,13 +119,60 @@ describe("Dear Future: Capsules Management ", () => {
         expect(error.message).to.include("already in use");
       }
     });
+
+    it("Should emit a config snapshot", async () => {
+      const signature = await program.methods
+        .getConfig()
+        .accounts({
+          config: configPda,
+        })
+        .rpc({ commitment: "confirmed" });
+
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const snapshot = [...parser.parseLogs(tx.meta.logMessages)].find((e) => e.name === "configSnapshot");
+
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(snapshot).to.not.be.undefined;
+      expect(snapshot.data.authority.toString()).to.equal(configAccount.authority.toString());
+      expect(snapshot.data.totalCapsules.toNumber()).to.equal(configAccount.totalCapsules.toNumber());
+      expect(snapshot.data.version).to.equal(configAccount.version);
+    });
+
+    it("Should emit the capsule address derived from creator and id", async () => {
+      const id = new anchor.BN(7);
+      const signature = await program.methods
+        .deriveCapsuleAddress(wallet.publicKey, id)
+        .rpc({ commitment: "confirmed" });
+
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const derived = [...parser.parseLogs(tx.meta.logMessages)].find((e) => e.name === "capsuleAddressDerived");
+
+      const [expectedPda, expectedBump] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          id.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      expect(derived).to.not.be.undefined;
+      expect(derived.data.capsule.toString()).to.equal(expectedPda.toString());
+      expect(derived.data.bump).to.equal(expectedBump);
+    });
   });
 
   describe("Capsule Creation", () => {
     it("Should create capsule successfully", async () => {
-      // Get current total capsules count
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      // Get the creator's next capsule ID
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       // Derive capsule PDA
       [capsulePda, capsuleBump] = PublicKey.findProgramAddressSync(
@@ -126,11 +185,12 @@ describe("Dear Future: Capsules Management ", () => {
       );
 
       const tx = await program.methods
-        .createCapsule(title, content, new anchor.BN(futureUnlockDate), null)
+        .createCapsule(title, content, new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: capsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -138,14 +198,15 @@ describe("Dear Future: Capsules Management ", () => {
       // Verify capsule was created correctly
       const capsuleAccount = await program.account.capsule.fetch(capsulePda);
       expect(capsuleAccount.creator.toString()).to.equal(wallet.publicKey.toString());
+      expect(capsuleAccount.lastModifiedBy.toString()).to.equal(wallet.publicKey.toString());
       expect(capsuleAccount.title).to.equal(title);
-      expect(capsuleAccount.encryptedUrl).to.be.null;
+      expect(capsuleAccount.encryptedUrls).to.be.empty;
+      expect(capsuleAccount.category).to.deep.equal({ personal: {} });
     });
 
     it("Should create capsule with encrypted URL successfully", async () => {
-      // Get current total capsules count
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      // Get the creator's next capsule ID
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       // Derive capsule PDA
       const [capsuleWithUrlPda] = PublicKey.findProgramAddressSync(
@@ -159,11 +220,12 @@ describe("Dear Future: Capsules Management ", () => {
 
       const encryptedUrl = "https://example.com/encrypted-content";
       const tx = await program.methods
-        .createCapsule(title, content, new anchor.BN(futureUnlockDate), encryptedUrl)
+        .createCapsule(title, content, new anchor.BN(futureUnlockDate), [encryptedUrl], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: capsuleWithUrlPda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -172,22 +234,63 @@ describe("Dear Future: Capsules Management ", () => {
       const capsuleAccount = await program.account.capsule.fetch(capsuleWithUrlPda);
       expect(capsuleAccount.creator.toString()).to.equal(wallet.publicKey.toString());
       expect(capsuleAccount.title).to.equal(title);
-      expect(capsuleAccount.encryptedUrl).to.equal(encryptedUrl);
+      expect(capsuleAccount.encryptedUrls).to.deep.equal([encryptedUrl]);
       expect(capsuleAccount.content).to.equal(content);
       expect(capsuleAccount.unlockDate.toNumber()).to.equal(futureUnlockDate);
       expect(capsuleAccount.isUnlocked).to.be.false;
       expect(capsuleAccount.mint).to.be.null;
       expect(capsuleAccount.id.toNumber()).to.equal(capsuleId);
 
-      // Verify config was updated
-      const updatedConfig = await program.account.config.fetch(configPda);
-      expect(updatedConfig.totalCapsules.toNumber()).to.equal(capsuleId + 1);
+      // Verify the creator's ID sequence advanced
+      expect((await nextCapsuleId(wallet.publicKey)).toNumber()).to.equal(capsuleId + 1);
+    });
+
+    it("Should store content hash and reject edits when hash is locked", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [lockedHashCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule(title, content, new anchor.BN(futureUnlockDate), [], null, true, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: lockedHashCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const expectedHash = createHash("sha256").update(title).update(content).digest();
+      const capsuleAccount = await program.account.capsule.fetch(lockedHashCapsulePda);
+      expect(Buffer.from(capsuleAccount.contentHash)).to.deep.equal(expectedHash);
+      expect(capsuleAccount.lockedHash).to.be.true;
+
+      try {
+        await program.methods
+          .updateCapsule(null, "Tampered content", null, [], [], false, null, null, null)
+          .accounts({
+            capsule: lockedHashCapsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ContentIsLocked");
+      }
     });
 
     it("Should fail with title too long", async () => {
       const longTitle = "x".repeat(101); // Max is 100
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       const [failCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -200,11 +303,12 @@ describe("Dear Future: Capsules Management ", () => {
 
       try {
         await program.methods
-          .createCapsule(longTitle, content, new anchor.BN(futureUnlockDate), null)
+          .createCapsule(longTitle, content, new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
           .accounts({
             config: configPda,
             capsule: failCapsulePda,
             creator: wallet.publicKey,
+            treasury: wallet.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -216,8 +320,7 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should fail with content too long", async () => {
       const longContent = "x".repeat(301); // Max is 300
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       const [failCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -230,11 +333,12 @@ describe("Dear Future: Capsules Management ", () => {
 
       try {
         await program.methods
-          .createCapsule(title, longContent, new anchor.BN(futureUnlockDate), null)
+          .createCapsule(title, longContent, new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
           .accounts({
             config: configPda,
             capsule: failCapsulePda,
             creator: wallet.publicKey,
+            treasury: wallet.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -246,8 +350,7 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should fail with encrypted URL too long", async () => {
       const longUrl = "x".repeat(501); // Max is 500
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       const [failCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -260,11 +363,12 @@ describe("Dear Future: Capsules Management ", () => {
 
       try {
         await program.methods
-          .createCapsule(title, content, new anchor.BN(futureUnlockDate), longUrl)
+          .createCapsule(title, content, new anchor.BN(futureUnlockDate), [longUrl], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
           .accounts({
             config: configPda,
             capsule: failCapsulePda,
             creator: wallet.publicKey,
+            treasury: wallet.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -274,9 +378,68 @@ describe("Dear Future: Capsules Management ", () => {
       }
     });
 
+    it("Should fail with malformed encrypted URL", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [failCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      for (const badUrl of ["ftp://example.com/file", "https://", "https://example.com/\nfile"]) {
+        try {
+          await program.methods
+            .createCapsule(title, content, new anchor.BN(futureUnlockDate), [badUrl], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+            .accounts({
+              config: configPda,
+              capsule: failCapsulePda,
+              creator: wallet.publicKey,
+              treasury: wallet.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+          expect.fail("Should have failed");
+        } catch (error) {
+          expect(error.message).to.include("InvalidUrlFormat");
+        }
+      }
+    });
+
+    it("Should fail with control characters in title", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [failCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createCapsule("Null\u0000byte", content, new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: failCapsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCharacters");
+      }
+    });
+
     it("Should fail with past unlock date", async () => {
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       const [failCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -289,11 +452,12 @@ describe("Dear Future: Capsules Management ", () => {
 
       try {
         await program.methods
-          .createCapsule(title, content, new anchor.BN(pastUnlockDate), null)
+          .createCapsule(title, content, new anchor.BN(pastUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
           .accounts({
             config: configPda,
             capsule: failCapsulePda,
             creator: wallet.publicKey,
+            treasury: wallet.publicKey,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -303,6 +467,37 @@ describe("Dear Future: Capsules Management ", () => {
       }
     });
 
+    it("Should fail with unlock date too far in the future", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [failCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      const farUnlockDate = futureUnlockDate + 101 * 365 * 24 * 60 * 60; // Past the ~100 year horizon
+
+      try {
+        await program.methods
+          .createCapsule(title, content, new anchor.BN(farUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: failCapsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnlockDateTooFar");
+      }
+    });
+
   });
 
   describe("Capsule Updates", () => {
@@ -310,10 +505,11 @@ describe("Dear Future: Capsules Management ", () => {
       const newContent = "Updated content for my future self.";
       
       await program.methods
-        .updateCapsule(newContent, null, null, false)
+        .updateCapsule(null, newContent, null, [], [], false, null, null, null)
         .accounts({
           capsule: capsulePda,
-          owner: wallet.publicKey,
+          updater: wallet.publicKey,
+          mint: null,
         })
         .rpc();
 
@@ -321,43 +517,257 @@ describe("Dear Future: Capsules Management ", () => {
       expect(capsuleAccount.content).to.equal(newContent);
     });
 
+    it("Should update capsule title successfully", async () => {
+      const newTitle = "My Corrected Future Capsule";
+
+      const signature = await program.methods
+        .updateCapsule(newTitle, null, null, [], [], false, null, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: wallet.publicKey,
+          mint: null,
+        })
+        .rpc({ commitment: "confirmed" });
+
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.title).to.equal(newTitle);
+
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const updated = [...parser.parseLogs(tx.meta.logMessages)].find((e) => e.name === "capsuleUpdated");
+      expect(updated).to.not.be.undefined;
+      expect(updated.data.titleUpdated).to.be.true;
+      expect(updated.data.changeMask).to.equal(0b1000);
+    });
+
+    it("Should update capsule category successfully", async () => {
+      await program.methods
+        .updateCapsule(null, null, null, [], [], false, { gift: {} }, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: wallet.publicKey,
+          mint: null,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.category).to.deep.equal({ gift: {} });
+    });
+
+    it("Should let a delegate update content but not the unlock date", async () => {
+      const assistant = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .setDelegate(assistant.publicKey)
+        .accounts({
+          capsule: capsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      const delegatedContent = "Edited by my assistant.";
+      await program.methods
+        .updateCapsule(null, delegatedContent, null, [], [], false, null, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: assistant.publicKey,
+          mint: null,
+        })
+        .signers([assistant])
+        .rpc();
+
+      let capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.content).to.equal(delegatedContent);
+      expect(capsuleAccount.owner.toString()).to.equal(wallet.publicKey.toString());
+      expect(capsuleAccount.lastModifiedBy.toString()).to.equal(assistant.publicKey.toString());
+
+      try {
+        await program.methods
+          .updateCapsule(null, null, new anchor.BN(capsuleAccount.unlockDate.toNumber() + 60), [], [], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: assistant.publicKey,
+          })
+          .signers([assistant])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("DelegateNotPermitted");
+      }
+
+      await program.methods
+        .setDelegate(null)
+        .accounts({
+          capsule: capsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.delegate).to.be.null;
+    });
+
+    it("Should archive, reject updates, and unarchive capsule", async () => {
+      await program.methods
+        .archiveCapsule()
+        .accounts({
+          capsule: capsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      let capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.archived).to.be.true;
+
+      try {
+        await program.methods
+          .updateCapsule(null, "Archived content", null, [], [], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ArchivedCapsuleImmutable");
+      }
+
+      await program.methods
+        .unarchiveCapsule()
+        .accounts({
+          capsule: capsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.archived).to.be.false;
+    });
+
+    it("Should fail to update title that is too long", async () => {
+      try {
+        await program.methods
+          .updateCapsule("x".repeat(101), null, null, [], [], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("TitleTooLong");
+      }
+    });
+
+    it("Should fail to update content with control characters", async () => {
+      try {
+        await program.methods
+          .updateCapsule(null, "Bell\u0007content", null, [], [], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidCharacters");
+      }
+    });
+
     it("Should update encrypted URL successfully", async () => {
       const newEncryptedUrl = "https://example.com/encrypted-content";
       
       await program.methods
-        .updateCapsule(null, null, newEncryptedUrl, false)
+        .updateCapsule(null, null, null, [newEncryptedUrl], [], false, null, null, null)
         .accounts({
           capsule: capsulePda,
-          owner: wallet.publicKey,
+          updater: wallet.publicKey,
+          mint: null,
         })
         .rpc();
 
       const capsuleAccount = await program.account.capsule.fetch(capsulePda);
-      expect(capsuleAccount.encryptedUrl).to.equal(newEncryptedUrl);
+      expect(capsuleAccount.encryptedUrls).to.deep.equal([newEncryptedUrl]);
     });
 
     it("Should remove encrypted URL successfully", async () => {
       
       await program.methods
-        .updateCapsule(null, null, null, true)
+        .updateCapsule(null, null, null, [], [0], false, null, null, null)
         .accounts({
           capsule: capsulePda,
-          owner: wallet.publicKey,
+          updater: wallet.publicKey,
+          mint: null,
         })
         .rpc();
 
       const capsuleAccount = await program.account.capsule.fetch(capsulePda)
-      expect(capsuleAccount.encryptedUrl).to.be.null;
+      expect(capsuleAccount.encryptedUrls).to.be.empty;
+    });
+
+    it("Should add and remove multiple encrypted URLs by index", async () => {
+      const urls = [
+        "https://example.com/photo",
+        "https://example.com/video",
+        "https://example.com/audio",
+      ];
+
+      await program.methods
+        .updateCapsule(null, null, null, urls, [], false, null, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: wallet.publicKey,
+          mint: null,
+        })
+        .rpc();
+
+      let capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.encryptedUrls).to.deep.equal(urls);
+
+      await program.methods
+        .updateCapsule(null, null, null, [], [0, 2], false, null, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: wallet.publicKey,
+          mint: null,
+        })
+        .rpc();
+
+      capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.encryptedUrls).to.deep.equal([urls[1]]);
+    });
+
+    it("Should fail to remove an out-of-range URL index", async () => {
+      try {
+        await program.methods
+          .updateCapsule(null, null, null, [], [5], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidUrlIndex");
+      }
     });
 
     it("Should extend unlock date successfully", async () => {
       const newUnlockDate = futureUnlockDate + 7200; // 2 hours later
       
       await program.methods
-        .updateCapsule(null, new anchor.BN(newUnlockDate), null, false)
+        .updateCapsule(null, null, new anchor.BN(newUnlockDate), [], [], false, null, null, null)
         .accounts({
           capsule: capsulePda,
-          owner: wallet.publicKey,
+          updater: wallet.publicKey,
+          mint: null,
         })
         .rpc();
 
@@ -370,10 +780,11 @@ describe("Dear Future: Capsules Management ", () => {
       
       try {
         await program.methods
-          .updateCapsule(null, new anchor.BN(shorterDate), null, false)
+          .updateCapsule(null, null, new anchor.BN(shorterDate), [], [], false, null, null, null)
           .accounts({
             capsule: capsulePda,
-            owner: wallet.publicKey,
+            updater: wallet.publicKey,
+            mint: null,
           })
           .rpc();
         expect.fail("Should have failed");
@@ -382,6 +793,38 @@ describe("Dear Future: Capsules Management ", () => {
       }
     });
 
+    it("Should shorten unlock date when allow_early is set", async () => {
+      const earlierDate = futureUnlockDate + 60;
+
+      await program.methods
+        .updateCapsule(null, null, new anchor.BN(earlierDate), [], [], true, null, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: wallet.publicKey,
+          mint: null,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.unlockDate.toNumber()).to.equal(earlierDate);
+    });
+
+    it("Should fail to move unlock date into the past even with allow_early", async () => {
+      try {
+        await program.methods
+          .updateCapsule(null, null, new anchor.BN(pastUnlockDate), [], [], true, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnlockDateMustBeFuture");
+      }
+    });
+
     it("Should fail to update from non-creator", async () => {
       // Create another keypair
       const nonCreator = anchor.web3.Keypair.generate();
@@ -395,10 +838,11 @@ describe("Dear Future: Capsules Management ", () => {
 
       try {
         await program.methods
-          .updateCapsule("Malicious update", null, null, false)
+          .updateCapsule(null, "Malicious update", null, [], [], false, null, null, null)
           .accounts({
             capsule: capsulePda,
-            owner: nonCreator.publicKey,
+            updater: nonCreator.publicKey,
+            mint: null,
           })
           .signers([nonCreator])
           .rpc();
@@ -417,8 +861,7 @@ describe("Dear Future: Capsules Management ", () => {
       // Create a capsule with future unlock date (15 seconds from now)
       futureUnlockDate = (await chainNow()) + 40; // 40 seconds from now
       
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       [futureCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -430,23 +873,96 @@ describe("Dear Future: Capsules Management ", () => {
       );
 
       await program.methods
-        .createCapsule("Future Capsule", "This can be unlocked in 15 seconds", new anchor.BN(futureUnlockDate), null)
+        .createCapsule("Future Capsule", "This can be unlocked in 15 seconds", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: futureCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
     });
 
+    it("Should reject views on a public capsule before it is unlocked", async () => {
+      await program.methods
+        .updateCapsule(null, null, null, [], [], false, null, true, null)
+        .accounts({
+          capsule: futureCapsulePda,
+          updater: wallet.publicKey,
+          mint: null,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(futureCapsulePda);
+      expect(capsuleAccount.isPublic).to.be.true;
+
+      try {
+        await program.methods
+          .registerView()
+          .accounts({
+            capsule: futureCapsulePda,
+            viewer: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleNotPublic");
+      }
+    });
+
+    it("Should report locked status before the unlock date", async () => {
+      const status = await program.methods
+        .getCapsuleStatus()
+        .accounts({ capsule: futureCapsulePda })
+        .view();
+      expect(status).to.deep.equal({ locked: {} });
+    });
+
+    it("Should report the unlock countdown without changing the capsule", async () => {
+      const before = await program.account.capsule.fetch(futureCapsulePda);
+      const stranger = anchor.web3.Keypair.generate();
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+
+      const countdownFor = async (caller: anchor.web3.Keypair | null) => {
+        const signature = await program.methods
+          .simulateUnlock()
+          .accounts({
+            capsule: futureCapsulePda,
+            caller: caller ? caller.publicKey : wallet.publicKey,
+          })
+          .signers(caller ? [caller] : [])
+          .rpc({ commitment: "confirmed" });
+        const tx = await provider.connection.getTransaction(signature, {
+          commitment: "confirmed",
+          maxSupportedTransactionVersion: 0,
+        });
+        return [...parser.parseLogs(tx.meta.logMessages)].find((e) => e.name === "unlockCountdown");
+      };
+
+      const ownerView = await countdownFor(null);
+      expect(ownerView).to.not.be.undefined;
+      expect(ownerView.data.secondsRemaining.toNumber()).to.equal(
+        before.unlockDate.toNumber() - ownerView.data.timestamp.toNumber()
+      );
+      expect(ownerView.data.secondsRemaining.toNumber()).to.be.greaterThan(0);
+      expect(ownerView.data.ready).to.be.false;
+      expect(ownerView.data.authorized).to.be.true;
+
+      const strangerView = await countdownFor(stranger);
+      expect(strangerView.data.authorized).to.be.false;
+
+      const after = await program.account.capsule.fetch(futureCapsulePda);
+      expect(after.updatedAt.toNumber()).to.equal(before.updatedAt.toNumber());
+    });
+
     it("Should fail to unlock capsule before time", async () => {
       try {
         await program.methods
           .unlockCapsule()
           .accounts({
             capsule: futureCapsulePda,
-            owner: wallet.publicKey,
+            unlocker: wallet.publicKey,
           })
           .rpc();
         expect.fail("Should have failed");
@@ -464,7 +980,7 @@ describe("Dear Future: Capsules Management ", () => {
         .unlockCapsule()
         .accounts({
           capsule: futureCapsulePda,
-          owner: wallet.publicKey,
+          unlocker: wallet.publicKey,
         })
         .rpc();
 
@@ -474,13 +990,95 @@ describe("Dear Future: Capsules Management ", () => {
       unlockedCapsuleID = capsuleAccount.id.toNumber();
     });
 
+    it("Should report unlocked status after unlock", async () => {
+      const status = await program.methods
+        .getCapsuleStatus()
+        .accounts({ capsule: futureCapsulePda })
+        .view();
+      expect(status).to.deep.equal({ unlocked: {} });
+    });
+
+    it("Should register a view on an unlocked public capsule", async () => {
+      const viewer = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .registerView()
+        .accounts({
+          capsule: futureCapsulePda,
+          viewer: viewer.publicKey,
+        })
+        .signers([viewer])
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(futureCapsulePda);
+      expect(capsuleAccount.viewCount.toNumber()).to.equal(1);
+    });
+
+    it("Should like and unlike an unlocked public capsule", async () => {
+      const reactor = anchor.web3.Keypair.generate();
+      const airdropTx = await provider.connection.requestAirdrop(
+        reactor.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+      const [reactionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("capsule_reaction"), reactor.publicKey.toBuffer(), futureCapsulePda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .likeCapsule()
+        .accounts({
+          capsule: futureCapsulePda,
+          capsuleReaction: reactionPda,
+          reactor: reactor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reactor])
+        .rpc();
+
+      let capsuleAccount = await program.account.capsule.fetch(futureCapsulePda);
+      expect(capsuleAccount.likeCount.toNumber()).to.equal(1);
+
+      try {
+        await program.methods
+          .likeCapsule()
+          .accounts({
+            capsule: futureCapsulePda,
+            capsuleReaction: reactionPda,
+            reactor: reactor.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([reactor])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+
+      await program.methods
+        .unlikeCapsule()
+        .accounts({
+          capsule: futureCapsulePda,
+          capsuleReaction: reactionPda,
+          reactor: reactor.publicKey,
+        })
+        .signers([reactor])
+        .rpc();
+
+      capsuleAccount = await program.account.capsule.fetch(futureCapsulePda);
+      expect(capsuleAccount.likeCount.toNumber()).to.equal(0);
+      expect(await provider.connection.getAccountInfo(reactionPda)).to.be.null;
+    });
+
     it("Should fail to update capsule after unlock", async () => {
       try {
         await program.methods
-          .updateCapsule("Cannot update after unlock", null, null, false)
+          .updateCapsule(null, "Cannot update after unlock", null, [], [], false, null, null, null)
           .accounts({
             capsule: futureCapsulePda,
-            owner: wallet.publicKey,
+            updater: wallet.publicKey,
+            mint: null,
           })
           .rpc();
         expect.fail("Should have failed");
@@ -491,6 +1089,54 @@ describe("Dear Future: Capsules Management ", () => {
   });
 
   describe("Capsule Closing", () => {
+    it("Should refuse to close a capsule that still has likes", async () => {
+      const reactor = anchor.web3.Keypair.generate();
+      const airdropTx = await provider.connection.requestAirdrop(
+        reactor.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+      const [reactionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("capsule_reaction"), reactor.publicKey.toBuffer(), unlockedCapsulePda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .likeCapsule()
+        .accounts({
+          capsule: unlockedCapsulePda,
+          capsuleReaction: reactionPda,
+          reactor: reactor.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([reactor])
+        .rpc();
+
+      try {
+        await program.methods
+          .closeCapsule()
+          .accounts({
+            capsule: unlockedCapsulePda,
+            owner: wallet.publicKey,
+            creator: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleHasReactions");
+      }
+
+      await program.methods
+        .unlikeCapsule()
+        .accounts({
+          capsule: unlockedCapsulePda,
+          capsuleReaction: reactionPda,
+          reactor: reactor.publicKey,
+        })
+        .signers([reactor])
+        .rpc();
+    });
+
     it("Should close unlocked capsule successfully", async () => {
       const initialBalance = await provider.connection.getBalance(wallet.publicKey);
       
@@ -500,6 +1146,7 @@ describe("Dear Future: Capsules Management ", () => {
         .accounts({
           capsule: unlockedCapsulePda,
           owner: wallet.publicKey,
+          creator: wallet.publicKey,
         })
         .rpc();
 
@@ -523,6 +1170,7 @@ describe("Dear Future: Capsules Management ", () => {
           .accounts({
             capsule: capsulePda, // This one is still locked
             owner: wallet.publicKey,
+            creator: wallet.publicKey,
           })
           .rpc();
         expect.fail("Should have failed");
@@ -533,8 +1181,7 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should fail to close from non-owner", async () => {
       // Create and unlock another capsule first
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
 
       console.log("Please wait few seconds for the capsule to be unlocked");
       // Create a capsule with future unlock date (5 seconds from now)
@@ -551,11 +1198,12 @@ describe("Dear Future: Capsules Management ", () => {
       );
 
       await program.methods
-        .createCapsule("Another Capsule", "For closing test", new anchor.BN(shortUnlockDate), null)
+        .createCapsule("Another Capsule", "For closing test", new anchor.BN(shortUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: newCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -567,7 +1215,7 @@ describe("Dear Future: Capsules Management ", () => {
         .unlockCapsule()
         .accounts({
           capsule: newCapsulePda,
-          owner: wallet.publicKey,
+          unlocker: wallet.publicKey,
         })
         .rpc();
 
@@ -585,6 +1233,7 @@ describe("Dear Future: Capsules Management ", () => {
           .accounts({
             capsule: newCapsulePda,
             owner: nonOwner.publicKey,
+            creator: wallet.publicKey,
           })
           .signers([nonOwner])
           .rpc();
@@ -612,8 +1261,7 @@ describe("Dear Future: Capsules Management ", () => {
       await provider.connection.confirmTransaction(airdropTx);
 
       // Create a capsule for transfer testing
-      const configAccount = await program.account.config.fetch(configPda);
-      transferCapsuleId = configAccount.totalCapsules.toNumber();
+      transferCapsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       [transferCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -627,11 +1275,12 @@ describe("Dear Future: Capsules Management ", () => {
       const transferUnlockDate = (await chainNow()) + 3600; // 1 hour from now
 
       await program.methods
-        .createCapsule("Transfer Test Capsule", "This capsule will be transferred", new anchor.BN(transferUnlockDate), null)
+        .createCapsule("Transfer Test Capsule", "This capsule will be transferred", new anchor.BN(transferUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: transferCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -639,11 +1288,21 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should transfer capsule successfully without mint", async () => {
       await program.methods
-        .transferCapsule(null)
+        .setDelegate(anchor.web3.Keypair.generate().publicKey)
+        .accounts({
+          capsule: transferCapsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .transferCapsule()
         .accounts({
           capsule: transferCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: newOwner.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -655,12 +1314,12 @@ describe("Dear Future: Capsules Management ", () => {
       expect(capsuleAccount.transferredAt).to.not.be.null;
       expect(capsuleAccount.mint).to.be.null;
       expect(capsuleAccount.mintCreator).to.be.null;
+      expect(capsuleAccount.delegate).to.be.null; // Previous owner's delegate is not inherited
     });
 
     it("Should transfer capsule successfully with mint address", async () => {
       // Create another capsule for this test
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [mintCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -674,24 +1333,27 @@ describe("Dear Future: Capsules Management ", () => {
       const mintUnlockDate = (await chainNow()) + 3600; // 1 hour from now
 
       await program.methods
-        .createCapsule("Mint Transfer Capsule", "This capsule will be transferred with mint", new anchor.BN(mintUnlockDate), null)
+        .createCapsule("Mint Transfer Capsule", "This capsule will be transferred with mint", new anchor.BN(mintUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { nft: {} })
         .accounts({
           config: configPda,
           capsule: mintCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
 
-      // Generate a fake mint address for testing
-      const fakeMintAddress = anchor.web3.Keypair.generate().publicKey;
+      // Create a real SPL mint to associate with the capsule
+      const mintAddress = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 0);
 
       await program.methods
-        .transferCapsule(fakeMintAddress)
+        .transferCapsule()
         .accounts({
           capsule: mintCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: newOwner.publicKey,
+          treasury: wallet.publicKey,
+          mint: mintAddress,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -700,7 +1362,7 @@ describe("Dear Future: Capsules Management ", () => {
       const capsuleAccount = await program.account.capsule.fetch(mintCapsulePda);
       expect(capsuleAccount.creator.toString()).to.equal(wallet.publicKey.toString());
       expect(capsuleAccount.owner.toString()).to.equal(newOwner.publicKey.toString());
-      expect(capsuleAccount.mint.toString()).to.equal(fakeMintAddress.toString());
+      expect(capsuleAccount.mint.toString()).to.equal(mintAddress.toString());
       expect(capsuleAccount.mintCreator.toString()).to.equal(wallet.publicKey.toString());
       expect(capsuleAccount.transferredAt).to.not.be.null;
     });
@@ -708,11 +1370,13 @@ describe("Dear Future: Capsules Management ", () => {
     it("Should fail to transfer to same owner", async () => {
       try {
         await program.methods
-          .transferCapsule(null)
+          .transferCapsule()
           .accounts({
             capsule: transferCapsulePda,
             currentOwner: newOwner.publicKey,
             newOwner: newOwner.publicKey, // Same as current owner
+            treasury: wallet.publicKey,
+            mint: null,
             systemProgram: SystemProgram.programId,
           })
           .signers([newOwner])
@@ -733,11 +1397,13 @@ describe("Dear Future: Capsules Management ", () => {
 
       try {
         await program.methods
-          .transferCapsule(null)
+          .transferCapsule()
           .accounts({
             capsule: transferCapsulePda,
             currentOwner: nonOwner.publicKey, // Not the current owner
             newOwner: wallet.publicKey,
+            treasury: wallet.publicKey,
+            mint: null,
             systemProgram: SystemProgram.programId,
           })
           .signers([nonOwner])
@@ -752,10 +1418,11 @@ describe("Dear Future: Capsules Management ", () => {
       const newContent = "Updated by new owner";
       
       await program.methods
-        .updateCapsule(newContent, null, null, false)
+        .updateCapsule(null, newContent, null, [], [], false, null, null, null)
         .accounts({
           capsule: transferCapsulePda,
-          owner: newOwner.publicKey, // New owner can update
+          updater: newOwner.publicKey, // New owner can update
+          mint: null,
         })
         .signers([newOwner])
         .rpc();
@@ -767,10 +1434,11 @@ describe("Dear Future: Capsules Management ", () => {
     it("Should prevent original creator from updating after transfer", async () => {
       try {
         await program.methods
-          .updateCapsule("Original creator trying to update", null, null, false)
+          .updateCapsule(null, "Original creator trying to update", null, [], [], false, null, null, null)
           .accounts({
             capsule: transferCapsulePda,
-            owner: wallet.publicKey, // Original creator, no longer owner
+            updater: wallet.publicKey, // Original creator, no longer owner
+            mint: null,
           })
           .rpc();
         expect.fail("Should have failed");
@@ -781,8 +1449,7 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should allow new owner to unlock capsule when time comes", async () => {
       // Create a capsule that can be unlocked soon
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       console.log("Please wait few seconds for the capsule to be unlocked");
       const unlockSoon = (await chainNow()) + 40; // 2 seconds from now
       
@@ -796,22 +1463,25 @@ describe("Dear Future: Capsules Management ", () => {
       );
 
       await program.methods
-        .createCapsule("Unlockable Transfer Capsule", "Can be unlocked soon", new anchor.BN(unlockSoon), null)
+        .createCapsule("Unlockable Transfer Capsule", "Can be unlocked soon", new anchor.BN(unlockSoon), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: unlockableCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
 
       // Transfer to new owner
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: unlockableCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: newOwner.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -824,7 +1494,7 @@ describe("Dear Future: Capsules Management ", () => {
         .unlockCapsule()
         .accounts({
           capsule: unlockableCapsulePda,
-          owner: newOwner.publicKey,
+          unlocker: newOwner.publicKey,
         })
         .signers([newOwner])
         .rpc();
@@ -835,8 +1505,7 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should allow new owner to close unlocked capsule", async () => {
       // Use the capsule we just unlocked
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber() - 1; // Last created capsule
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber() - 1; // Last created capsule
       
       const [closableCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -854,6 +1523,7 @@ describe("Dear Future: Capsules Management ", () => {
         .accounts({
           capsule: closableCapsulePda,
           owner: newOwner.publicKey,
+          creator: wallet.publicKey,
         })
         .signers([newOwner])
         .rpc();
@@ -886,8 +1556,7 @@ describe("Dear Future: Capsules Management ", () => {
       await provider.connection.confirmTransaction(airdropTx);
 
       // Create a capsule for ownership testing
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const ownershipUnlockDate = (await chainNow()) + 3600; // 1 hour from now
       
@@ -901,22 +1570,25 @@ describe("Dear Future: Capsules Management ", () => {
       );
 
       await program.methods
-        .createCapsule("Ownership Test", "Testing owner vs creator", new anchor.BN(ownershipUnlockDate), null)
+        .createCapsule("Ownership Test", "Testing owner vs creator", new anchor.BN(ownershipUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: ownershipCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
 
       // Transfer to third party
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: ownershipCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: thirdParty.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -933,10 +1605,11 @@ describe("Dear Future: Capsules Management ", () => {
       // Creator should fail
       try {
         await program.methods
-          .updateCapsule("Creator update attempt", null, null, false)
+          .updateCapsule(null, "Creator update attempt", null, [], [], false, null, null, null)
           .accounts({
             capsule: ownershipCapsulePda,
-            owner: wallet.publicKey, // Creator trying to update
+            updater: wallet.publicKey, // Creator trying to update
+            mint: null,
           })
           .rpc();
         expect.fail("Should have failed");
@@ -946,10 +1619,11 @@ describe("Dear Future: Capsules Management ", () => {
 
       // Owner should succeed
       await program.methods
-        .updateCapsule("Owner update success", null, null, false)
+        .updateCapsule(null, "Owner update success", null, [], [], false, null, null, null)
         .accounts({
           capsule: ownershipCapsulePda,
-          owner: thirdParty.publicKey, // Current owner updating
+          updater: thirdParty.publicKey, // Current owner updating
+          mint: null,
         })
         .signers([thirdParty])
         .rpc();
@@ -960,8 +1634,7 @@ describe("Dear Future: Capsules Management ", () => {
 
     it("Should only allow owner to unlock capsule, not creator", async () => {
       // Create an unlockable capsule
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       console.log("Please wait few seconds for the capsule to be unlocked");
       const unlockSoon = (await chainNow()) + 40; // 2 seconds from now
       
@@ -975,22 +1648,25 @@ describe("Dear Future: Capsules Management ", () => {
       );
 
       await program.methods
-        .createCapsule("Unlock Test", "For unlock access test", new anchor.BN(unlockSoon), null)
+        .createCapsule("Unlock Test", "For unlock access test", new anchor.BN(unlockSoon), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: unlockTestCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
 
       // Transfer to third party
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: unlockTestCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: thirdParty.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -1004,7 +1680,7 @@ describe("Dear Future: Capsules Management ", () => {
           .unlockCapsule()
           .accounts({
             capsule: unlockTestCapsulePda,
-            owner: wallet.publicKey, // Creator trying to unlock
+            unlocker: wallet.publicKey, // Creator trying to unlock
           })
           .rpc();
         expect.fail("Should have failed");
@@ -1017,7 +1693,7 @@ describe("Dear Future: Capsules Management ", () => {
         .unlockCapsule()
         .accounts({
           capsule: unlockTestCapsulePda,
-          owner: thirdParty.publicKey, // Current owner unlocking
+          unlocker: thirdParty.publicKey, // Current owner unlocking
         })
         .signers([thirdParty])
         .rpc();
@@ -1025,5 +1701,47 @@ describe("Dear Future: Capsules Management ", () => {
       const capsuleAccount = await program.account.capsule.fetch(unlockTestCapsulePda);
       expect(capsuleAccount.isUnlocked).to.be.true;
     });
+
+    it("Should allow the designated recipient to unlock capsule", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      const unlockSoon = (await chainNow()) + 10;
+
+      const [recipientCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Recipient Test", "For the recipient only", new anchor.BN(unlockSoon), [], thirdParty.publicKey, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: recipientCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await waitForChainTime(unlockSoon + 1);
+
+      // Recipient is not the owner but should still be able to unlock
+      await program.methods
+        .unlockCapsule()
+        .accounts({
+          capsule: recipientCapsulePda,
+          unlocker: thirdParty.publicKey,
+        })
+        .signers([thirdParty])
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(recipientCapsulePda);
+      expect(capsuleAccount.isUnlocked).to.be.true;
+      expect(capsuleAccount.recipient.toString()).to.equal(thirdParty.publicKey.toString());
+      expect(capsuleAccount.owner.toString()).to.equal(wallet.publicKey.toString());
+    });
   });
 });
\ No newline at end of file
@@ -2,7 +2,9 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { DearFuture } from "../target/types/dear_future";
 import { expect } from "chai";
+import { createMint } from "@solana/spl-token";
 import { PublicKey, SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { createHash } from "crypto";
 
 describe("Dear Future: Edge Cases and Security Tests", () => {
   const provider = anchor.AnchorProvider.env();
@@ -29,6 +31,16 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
     }
   }
 
+  // Capsule IDs are allocated per creator from their CreatorStats account
+  async function nextCapsuleId(creator: PublicKey): Promise<anchor.BN> {
+    const [creatorStatsPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("creator_stats"), creator.toBuffer()],
+      program.programId
+    );
+    const stats = await program.account.creatorStats.fetchNullable(creatorStatsPda);
+    return stats ? stats.nextId : new anchor.BN(0);
+  }
+
   const program = anchor.workspace.DearFuture as Program<DearFuture>;
   const wallet = provider.wallet as anchor.Wallet;
 
@@ -51,8 +63,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
   describe("Multiple Transfer Chain Tests", () => {
     it("Should handle multiple transfers correctly", async () => {
       // Create a capsule
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [multiTransferCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -64,11 +75,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
 
       await program.methods
-        .createCapsule("Multi Transfer Test", "This will be transferred multiple times", new anchor.BN(futureUnlockDate), null)
+        .createCapsule("Multi Transfer Test", "This will be transferred multiple times", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: multiTransferCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -89,32 +101,38 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
       // Transfer chain: wallet -> user1 -> user2 -> user3
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: multiTransferCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: user1.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
 
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: multiTransferCapsulePda,
           currentOwner: user1.publicKey,
           newOwner: user2.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user1])
         .rpc();
 
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: multiTransferCapsulePda,
           currentOwner: user2.publicKey,
           newOwner: user3.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .signers([user2])
@@ -128,10 +146,11 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
       // Only user3 should be able to update now
       await program.methods
-        .updateCapsule("Updated by final owner", null, null, false)
+        .updateCapsule(null, "Updated by final owner", null, [], [], false, null, null, null)
         .accounts({
           capsule: multiTransferCapsulePda,
-          owner: user3.publicKey,
+          updater: user3.publicKey,
+          mint: null,
         })
         .signers([user3])
         .rpc();
@@ -142,8 +161,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
     it("Should preserve all capsule data during transfer", async () => {
       // Create a capsule with all possible data
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [preserveDataCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -160,11 +178,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       const originalUnlockDate = futureUnlockDate + 3600; // 1 hour later
 
       await program.methods
-        .createCapsule(originalTitle, originalContent, new anchor.BN(originalUnlockDate), originalUrl)
+        .createCapsule(originalTitle, originalContent, new anchor.BN(originalUnlockDate), [originalUrl], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: preserveDataCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -181,11 +200,13 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       await provider.connection.confirmTransaction(airdropTx);
 
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: preserveDataCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: newOwner.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -194,7 +215,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       const transferredCapsule = await program.account.capsule.fetch(preserveDataCapsulePda);
       expect(transferredCapsule.title).to.equal(originalTitle);
       expect(transferredCapsule.content).to.equal(originalContent);
-      expect(transferredCapsule.encryptedUrl).to.equal(originalUrl);
+      expect(transferredCapsule.encryptedUrls).to.deep.equal([originalUrl]);
       expect(transferredCapsule.unlockDate.toNumber()).to.equal(originalUnlockDate);
       expect(transferredCapsule.creator.toString()).to.equal(wallet.publicKey.toString());
       expect(transferredCapsule.owner.toString()).to.equal(newOwner.publicKey.toString());
@@ -208,8 +229,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
   describe("Input Validation Edge Cases", () => {
     it("Should handle empty string inputs correctly", async () => {
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [emptyCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -222,11 +242,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
       // Empty title should be allowed (it's just a zero-length string)
       await program.methods
-        .createCapsule("", "Non-empty content", new anchor.BN(futureUnlockDate), null)
+        .createCapsule("", "Non-empty content", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: emptyCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -240,10 +261,9 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       // Test exact boundary values
       const maxTitle = "x".repeat(100); // Exactly max length
       const maxContent = "y".repeat(300); // Exactly max length
-      const maxUrl = "z".repeat(500); // Exactly max length
+      const maxUrl = "https://" + "z".repeat(492); // Exactly max length
 
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [boundaryCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -255,11 +275,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
 
       await program.methods
-        .createCapsule(maxTitle, maxContent, new anchor.BN(futureUnlockDate), maxUrl)
+        .createCapsule(maxTitle, maxContent, new anchor.BN(futureUnlockDate), [maxUrl], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: boundaryCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -267,7 +288,81 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       const capsuleAccount = await program.account.capsule.fetch(boundaryCapsulePda);
       expect(capsuleAccount.title).to.equal(maxTitle);
       expect(capsuleAccount.content).to.equal(maxContent);
-      expect(capsuleAccount.encryptedUrl).to.equal(maxUrl);
+      expect(capsuleAccount.encryptedUrls).to.deep.equal([maxUrl]);
+    });
+
+    it("Should require either content or a content CID", async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createCapsule("No Content", "", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: capsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NoContentProvided");
+      }
+
+      try {
+        await program.methods
+          .createCapsule("Too Short", "Hi", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: capsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ContentTooShort");
+      }
+
+      const cid = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+      await program.methods
+        .createCapsule("Long Letter", "", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, cid, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.content).to.equal("");
+      expect(capsuleAccount.contentCid).to.equal(cid);
+
+      try {
+        await program.methods
+          .updateCapsule(null, null, null, [], [], false, null, null, "")
+          .accounts({
+            capsule: capsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NoContentProvided");
+      }
     });
   });
 
@@ -275,8 +370,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
     it("Should fail transfer with invalid PDA derivation", async () => {
       // Try to use wrong creator in PDA derivation
       const wrongCreator = anchor.web3.Keypair.generate();
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [wrongPda] = PublicKey.findProgramAddressSync(
         [
@@ -296,11 +390,13 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
       try {
         await program.methods
-          .transferCapsule(null)
+          .transferCapsule()
           .accounts({
             capsule: wrongPda, // Wrong PDA
             currentOwner: wallet.publicKey,
             newOwner: newOwner.publicKey,
+            treasury: wallet.publicKey,
+            mint: null,
             systemProgram: SystemProgram.programId,
           })
           .rpc();
@@ -313,8 +409,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
 
     it("Should prevent unauthorized mint updates", async () => {
       // Create a capsule
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [mintTestCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -326,11 +421,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
 
       await program.methods
-        .createCapsule("Mint Test", "For mint security testing", new anchor.BN(futureUnlockDate), null)
+        .createCapsule("Mint Test", "For mint security testing", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { nft: {} })
         .accounts({
           config: configPda,
           capsule: mintTestCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -343,14 +439,16 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
       await provider.connection.confirmTransaction(airdropTx);
 
-      const mintAddress = anchor.web3.Keypair.generate().publicKey;
+      const mintAddress = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 0);
 
       await program.methods
-        .transferCapsule(mintAddress)
+        .transferCapsule()
         .accounts({
           capsule: mintTestCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: newOwner.publicKey,
+          treasury: wallet.publicKey,
+          mint: mintAddress,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -360,7 +458,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       expect(capsuleAccount.mint.toString()).to.equal(mintAddress.toString());
       expect(capsuleAccount.mintCreator.toString()).to.equal(wallet.publicKey.toString());
 
-      // Try to transfer again with different mint (should work but not overwrite existing mint info)
+      // Try to transfer again with a different mint (should be rejected, the mint cannot be replaced)
       const anotherOwner = anchor.web3.Keypair.generate();
       const airdropTx2 = await provider.connection.requestAirdrop(
         anotherOwner.publicKey,
@@ -368,31 +466,215 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
       await provider.connection.confirmTransaction(airdropTx2);
 
-      const newMintAddress = anchor.web3.Keypair.generate().publicKey;
+      const newMintAddress = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 0);
+
+      try {
+        await program.methods
+          .transferCapsule()
+          .accounts({
+            capsule: mintTestCapsulePda,
+            currentOwner: newOwner.publicKey,
+            newOwner: anotherOwner.publicKey,
+            treasury: wallet.publicKey,
+            mint: newMintAddress,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([newOwner])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleAlreadyHasMint");
+      }
+
+      // Mint info should be unchanged
+      const finalCapsuleAccount = await program.account.capsule.fetch(mintTestCapsulePda);
+      expect(finalCapsuleAccount.mint.toString()).to.equal(mintAddress.toString());
+      expect(finalCapsuleAccount.mintCreator.toString()).to.equal(wallet.publicKey.toString());
+    });
+
+    it("Should reject a mint account not owned by the token program", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
 
       await program.methods
-        .transferCapsule(newMintAddress)
+        .createCapsule("Bogus Mint", "Mint is a wallet", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { nft: {} })
         .accounts({
-          capsule: mintTestCapsulePda,
-          currentOwner: newOwner.publicKey,
-          newOwner: anotherOwner.publicKey,
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .transferCapsule()
+          .accounts({
+            capsule: capsulePda,
+            currentOwner: wallet.publicKey,
+            newOwner: anchor.web3.Keypair.generate().publicKey,
+            treasury: wallet.publicKey,
+            mint: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTokenAccount");
+      }
+    });
+
+    it("Should reject attaching a mint to a text capsule", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Text Only", "Words, not tokens", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const mintAddress = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 0);
+
+      try {
+        await program.methods
+          .transferCapsule()
+          .accounts({
+            capsule: capsulePda,
+            currentOwner: wallet.publicKey,
+            newOwner: anchor.web3.Keypair.generate().publicKey,
+            treasury: wallet.publicKey,
+            mint: mintAddress,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CannotMintTextCapsule");
+      }
+
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.capsuleType).to.deep.equal({ text: {} });
+      expect(capsuleAccount.mint).to.be.null;
+    });
+
+    it("Should require the mint account to change a minted capsule's unlock date", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Minted Extension", "Tokenized and still locked", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { nft: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const newOwner = anchor.web3.Keypair.generate();
+      const airdropTx = await provider.connection.requestAirdrop(
+        newOwner.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+
+      const mintAddress = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 0);
+
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: capsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: newOwner.publicKey,
+          treasury: wallet.publicKey,
+          mint: mintAddress,
           systemProgram: SystemProgram.programId,
         })
+        .rpc();
+
+      const extendedDate = futureUnlockDate + 3600;
+
+      try {
+        await program.methods
+          .updateCapsule(null, null, new anchor.BN(extendedDate), [], [], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: newOwner.publicKey,
+            mint: null,
+          })
+          .signers([newOwner])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("MintRequiredForUpdate");
+      }
+
+      try {
+        await program.methods
+          .updateCapsule(null, null, new anchor.BN(extendedDate), [], [], false, null, null, null)
+          .accounts({
+            capsule: capsulePda,
+            updater: newOwner.publicKey,
+            mint: anchor.web3.Keypair.generate().publicKey,
+          })
+          .signers([newOwner])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTokenAccount");
+      }
+
+      await program.methods
+        .updateCapsule(null, null, new anchor.BN(extendedDate), [], [], false, null, null, null)
+        .accounts({
+          capsule: capsulePda,
+          updater: newOwner.publicKey,
+          mint: mintAddress,
+        })
         .signers([newOwner])
         .rpc();
 
-      // Mint info should be updated with new mint and new mint creator
-      const finalCapsuleAccount = await program.account.capsule.fetch(mintTestCapsulePda);
-      expect(finalCapsuleAccount.mint.toString()).to.equal(newMintAddress.toString());
-      expect(finalCapsuleAccount.mintCreator.toString()).to.equal(newOwner.publicKey.toString()); // New mint creator
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      expect(capsuleAccount.unlockDate.toNumber()).to.equal(extendedDate);
     });
   });
 
   describe("Concurrency and Race Conditions", () => {
     it("Should handle concurrent operations correctly", async () => {
       // Create a capsule for concurrent testing
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [concurrentCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -404,11 +686,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
 
       await program.methods
-        .createCapsule("Concurrent Test", "For concurrent operations", new anchor.BN(futureUnlockDate), null)
+        .createCapsule("Concurrent Test", "For concurrent operations", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
         .accounts({
           config: configPda,
           capsule: concurrentCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -416,18 +699,20 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       // Try multiple updates concurrently (these should all succeed if executed sequentially)
       const updates = [
         program.methods
-          .updateCapsule("Update 1", null, null, false)
+          .updateCapsule(null, "Update 1", null, [], [], false, null, null, null)
           .accounts({
             capsule: concurrentCapsulePda,
-            owner: wallet.publicKey,
+            updater: wallet.publicKey,
+            mint: null,
           })
           .rpc(),
         
         program.methods
-          .updateCapsule("Update 2", null, null, false)
+          .updateCapsule(null, "Update 2", null, [], [], false, null, null, null)
           .accounts({
             capsule: concurrentCapsulePda,
-            owner: wallet.publicKey,
+            updater: wallet.publicKey,
+            mint: null,
           })
           .rpc(),
       ];
@@ -448,8 +733,7 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
   describe("NFT Integration Tests", () => {
     it("Should handle mint address correctly in transfers", async () => {
       // Create a capsule
-      const configAccount = await program.account.config.fetch(configPda);
-      const capsuleId = configAccount.totalCapsules.toNumber();
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
       
       const [nftCapsulePda] = PublicKey.findProgramAddressSync(
         [
@@ -461,11 +745,12 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       );
 
       await program.methods
-        .createCapsule("NFT Integration Test", "Testing NFT functionality", new anchor.BN(futureUnlockDate), null)
+        .createCapsule("NFT Integration Test", "Testing NFT functionality", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { nft: {} })
         .accounts({
           config: configPda,
           capsule: nftCapsulePda,
           creator: wallet.publicKey,
+          treasury: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -476,11 +761,13 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       await provider.connection.confirmTransaction(airdropTx);
 
       await program.methods
-        .transferCapsule(null)
+        .transferCapsule()
         .accounts({
           capsule: nftCapsulePda,
           currentOwner: wallet.publicKey,
           newOwner: owner1.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
           systemProgram: SystemProgram.programId,
         })
         .rpc();
@@ -494,14 +781,16 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       airdropTx = await provider.connection.requestAirdrop(owner2.publicKey, LAMPORTS_PER_SOL);
       await provider.connection.confirmTransaction(airdropTx);
 
-      const mintAddress = anchor.web3.Keypair.generate().publicKey;
+      const mintAddress = await createMint(provider.connection, wallet.payer, wallet.publicKey, null, 0);
 
       await program.methods
-        .transferCapsule(mintAddress)
+        .transferCapsule()
         .accounts({
           capsule: nftCapsulePda,
           currentOwner: owner1.publicKey,
           newOwner: owner2.publicKey,
+          treasury: wallet.publicKey,
+          mint: mintAddress,
           systemProgram: SystemProgram.programId,
         })
         .signers([owner1])
@@ -513,4 +802,2456 @@ describe("Dear Future: Edge Cases and Security Tests", () => {
       expect(capsuleAccount.owner.toString()).to.equal(owner2.publicKey.toString());
     });
   });
+
+  describe("Program Pause", () => {
+    it("Should reject capsule creation while paused", async () => {
+      await program.methods
+        .setPause(true)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.paused).to.be.true;
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [pausedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      try {
+        await program.methods
+          .createCapsule("Paused", "Should not be created", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: pausedCapsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ProgramPaused");
+      } finally {
+        await program.methods
+          .setPause(false)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+      }
+
+      const resumedConfig = await program.account.config.fetch(configPda);
+      expect(resumedConfig.paused).to.be.false;
+    });
+
+    it("Should fail to pause from non-authority", async () => {
+      const attacker = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .setPause(true)
+          .accounts({
+            config: configPda,
+            authority: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAuthority");
+      }
+    });
+  });
+
+  describe("Authority Emergency Unlock", () => {
+    let lockedCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      [lockedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Estate Capsule", "Needs an early unlock", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: lockedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should fail emergency unlock from non-authority", async () => {
+      const attacker = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .authorityUnlock()
+          .accounts({
+            config: configPda,
+            capsule: lockedCapsulePda,
+            authority: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAuthority");
+      }
+    });
+
+    it("Should let the authority unlock before the unlock date", async () => {
+      await program.methods
+        .authorityUnlock()
+        .accounts({
+          config: configPda,
+          capsule: lockedCapsulePda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(lockedCapsulePda);
+      expect(capsuleAccount.isUnlocked).to.be.true;
+      expect(capsuleAccount.unlockDate.toNumber()).to.equal(futureUnlockDate);
+    });
+
+    it("Should fail emergency unlock of an already unlocked capsule", async () => {
+      try {
+        await program.methods
+          .authorityUnlock()
+          .accounts({
+            config: configPda,
+            capsule: lockedCapsulePda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleAlreadyUnlocked");
+      }
+    });
+  });
+
+  describe("Transfer Fees", () => {
+    const transferFee = 1_000_000; // Above the rent-exempt minimum for a fresh treasury
+    const treasury = anchor.web3.Keypair.generate();
+
+    after(async () => {
+      // Restore the default fee-free config for other suites
+      await program.methods
+        .setFeeConfig(new anchor.BN(0), new anchor.BN(0), wallet.publicKey)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Should route the transfer fee to the treasury", async () => {
+      await program.methods
+        .setFeeConfig(new anchor.BN(transferFee), new anchor.BN(0), treasury.publicKey)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.transferFeeLamports.toNumber()).to.equal(transferFee);
+      expect(configAccount.treasury.toString()).to.equal(treasury.publicKey.toString());
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [feeCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Fee Test", "Transfers cost a fee", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: feeCapsulePda,
+          creator: wallet.publicKey,
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const recipient = anchor.web3.Keypair.generate();
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: feeCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: recipient.publicKey,
+          treasury: treasury.publicKey,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const treasuryBalance = await provider.connection.getBalance(treasury.publicKey);
+      expect(treasuryBalance).to.equal(transferFee);
+    });
+
+    it("Should fail transfer with a mismatched treasury", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Wrong Treasury", "Should not transfer", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .transferCapsule()
+          .accounts({
+            capsule: capsulePda,
+            currentOwner: wallet.publicKey,
+            newOwner: anchor.web3.Keypair.generate().publicKey,
+            treasury: wallet.publicKey,
+            mint: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidTreasury");
+      }
+    });
+
+    it("Should route the creation fee to the treasury", async () => {
+      const createFee = 2_000_000;
+      await program.methods
+        .setFeeConfig(new anchor.BN(transferFee), new anchor.BN(createFee), treasury.publicKey)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.createFeeLamports.toNumber()).to.equal(createFee);
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      const balanceBefore = await provider.connection.getBalance(treasury.publicKey);
+
+      await program.methods
+        .createCapsule("Creation Fee", "Creating costs a fee", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: treasury.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const balanceAfter = await provider.connection.getBalance(treasury.publicKey);
+      expect(balanceAfter - balanceBefore).to.equal(createFee);
+    });
+  });
+
+  describe("Close Config", () => {
+    it("Should refuse to close config with capsules unless forced", async () => {
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.totalCapsules.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await program.methods
+          .closeConfig(false)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ConfigHasCapsules");
+      }
+
+      const stillThere = await program.account.config.fetch(configPda);
+      expect(stillThere.totalCapsules.toNumber()).to.equal(configAccount.totalCapsules.toNumber());
+    });
+
+    it("Should fail to close config from non-authority", async () => {
+      const attacker = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .closeConfig(true)
+          .accounts({
+            config: configPda,
+            authority: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAuthority");
+      }
+    });
+  });
+
+  describe("Config Authority Transfer", () => {
+    it("Should reject transferring authority to the same key or the default key", async () => {
+      for (const newAuthority of [wallet.publicKey, PublicKey.default]) {
+        try {
+          await program.methods
+            .setAuthority(newAuthority)
+            .accounts({
+              config: configPda,
+              authority: wallet.publicKey,
+            })
+            .rpc();
+          expect.fail("Should have failed");
+        } catch (error) {
+          expect(error.message).to.include("InvalidNewAuthority");
+        }
+      }
+    });
+
+    it("Should transfer authority to a new key and back", async () => {
+      const newAuthority = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .setAuthority(newAuthority.publicKey)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      let configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.authority.toString()).to.equal(newAuthority.publicKey.toString());
+
+      try {
+        await program.methods
+          .setPause(true)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("UnauthorizedAuthority");
+      }
+
+      await program.methods
+        .setAuthority(wallet.publicKey)
+        .accounts({
+          config: configPda,
+          authority: newAuthority.publicKey,
+        })
+        .signers([newAuthority])
+        .rpc();
+
+      configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.authority.toString()).to.equal(wallet.publicKey.toString());
+    });
+  });
+
+  describe("Co-Owners", () => {
+    let coOwnerCapsulePda: PublicKey;
+    let unlockSoon: number;
+    const coOwner = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      unlockSoon = (await chainNow()) + 5;
+
+      [coOwnerCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Family Capsule", "Shared with the family", new anchor.BN(unlockSoon), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: coOwnerCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should add a co-owner and reject duplicates", async () => {
+      await program.methods
+        .addCoOwner(coOwner.publicKey)
+        .accounts({
+          capsule: coOwnerCapsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(coOwnerCapsulePda);
+      expect(capsuleAccount.coOwners.map((k) => k.toString())).to.deep.equal([coOwner.publicKey.toString()]);
+
+      try {
+        await program.methods
+          .addCoOwner(coOwner.publicKey)
+          .accounts({
+            capsule: coOwnerCapsulePda,
+            owner: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CoOwnerAlreadyExists");
+      }
+    });
+
+    it("Should fail when a co-owner tries to manage co-owners", async () => {
+      try {
+        await program.methods
+          .removeCoOwner(coOwner.publicKey)
+          .accounts({
+            capsule: coOwnerCapsulePda,
+            owner: coOwner.publicKey,
+          })
+          .signers([coOwner])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NotOwner");
+      }
+    });
+
+    it("Should let a co-owner unlock the capsule", async () => {
+      await waitForChainTime(unlockSoon + 1);
+
+      await program.methods
+        .unlockCapsule()
+        .accounts({
+          capsule: coOwnerCapsulePda,
+          unlocker: coOwner.publicKey,
+        })
+        .signers([coOwner])
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(coOwnerCapsulePda);
+      expect(capsuleAccount.isUnlocked).to.be.true;
+    });
+
+    it("Should remove a co-owner and reject unknown keys", async () => {
+      await program.methods
+        .removeCoOwner(coOwner.publicKey)
+        .accounts({
+          capsule: coOwnerCapsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(coOwnerCapsulePda);
+      expect(capsuleAccount.coOwners).to.be.empty;
+
+      try {
+        await program.methods
+          .removeCoOwner(coOwner.publicKey)
+          .accounts({
+            capsule: coOwnerCapsulePda,
+            owner: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CoOwnerNotFound");
+      }
+    });
+  });
+
+  describe("Batch Capsule Creation", () => {
+    it("Should create several capsules in one transaction", async () => {
+      const totalBefore = (await program.account.config.fetch(configPda)).totalCapsules.toNumber();
+      const firstId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      const inputs = [
+        { title: "Starter 1", content: "Welcome aboard", unlockDate: new anchor.BN(futureUnlockDate), encryptedUrls: [], capsuleType: { text: {} } },
+        { title: "Starter 2", content: "One year in", unlockDate: new anchor.BN(futureUnlockDate + 60), encryptedUrls: ["https://example.com/encrypted"], capsuleType: { text: {} } },
+      ];
+
+      const capsulePdas = inputs.map((_, i) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("capsule"),
+            wallet.publicKey.toBuffer(),
+            new anchor.BN(firstId + i).toBuffer("le", 8),
+          ],
+          program.programId
+        )[0]
+      );
+
+      await program.methods
+        .createCapsulesBatch(inputs)
+        .accounts({
+          config: configPda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(capsulePdas.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .rpc();
+
+      for (let i = 0; i < inputs.length; i++) {
+        const capsuleAccount = await program.account.capsule.fetch(capsulePdas[i]);
+        expect(capsuleAccount.title).to.equal(inputs[i].title);
+        expect(capsuleAccount.id.toNumber()).to.equal(firstId + i);
+        expect(capsuleAccount.owner.toString()).to.equal(wallet.publicKey.toString());
+      }
+
+      const updatedConfig = await program.account.config.fetch(configPda);
+      expect(updatedConfig.totalCapsules.toNumber()).to.equal(totalBefore + inputs.length);
+      expect((await nextCapsuleId(wallet.publicKey)).toNumber()).to.equal(firstId + inputs.length);
+    });
+
+    it("Should fail when remaining accounts do not match the batch", async () => {
+      try {
+        await program.methods
+          .createCapsulesBatch([
+            { title: "Orphan", content: "No account passed", unlockDate: new anchor.BN(futureUnlockDate), encryptedUrls: [], capsuleType: { text: {} } },
+          ])
+          .accounts({
+            config: configPda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidBatchAccounts");
+      }
+    });
+
+    it("Should create a capsule whose address was already funded", async () => {
+      const nextId = await nextCapsuleId(wallet.publicKey);
+      const [prefundedPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          nextId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      // A stray transfer to the next PDA must not block the creator's batch
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          SystemProgram.transfer({
+            fromPubkey: wallet.publicKey,
+            toPubkey: prefundedPda,
+            lamports: 1_000_000,
+          })
+        )
+      );
+
+      await program.methods
+        .createCapsulesBatch([
+          { title: "Prefunded", content: "Someone got here first", unlockDate: new anchor.BN(futureUnlockDate), encryptedUrls: [], capsuleType: { text: {} } },
+        ])
+        .accounts({
+          config: configPda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([{ pubkey: prefundedPda, isWritable: true, isSigner: false }])
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(prefundedPda);
+      expect(capsuleAccount.title).to.equal("Prefunded");
+      expect(capsuleAccount.id.toNumber()).to.equal(nextId.toNumber());
+    });
+  });
+
+  describe("Creation Rate Limit", () => {
+    it("Should reject creations beyond the per-window cap", async () => {
+      const spammer = anchor.web3.Keypair.generate();
+      const airdropTx = await provider.connection.requestAirdrop(
+        spammer.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+
+      await program.methods
+        .setRateLimit(new anchor.BN(3600), 1)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsulePdaFor = async () => {
+        const capsuleId = await nextCapsuleId(spammer.publicKey);
+        return PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("capsule"),
+            spammer.publicKey.toBuffer(),
+            capsuleId.toBuffer("le", 8),
+          ],
+          program.programId
+        )[0];
+      };
+
+      try {
+        await program.methods
+          .createCapsule("First", "Within the limit", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: await capsulePdaFor(),
+            creator: spammer.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([spammer])
+          .rpc();
+
+        const [creatorStatsPda] = PublicKey.findProgramAddressSync(
+          [Buffer.from("creator_stats"), spammer.publicKey.toBuffer()],
+          program.programId
+        );
+        const stats = await program.account.creatorStats.fetch(creatorStatsPda);
+        expect(stats.windowCount).to.equal(1);
+        expect(stats.createdCount.toNumber()).to.equal(1);
+
+        try {
+          await program.methods
+            .createCapsule("Second", "Over the limit", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+            .accounts({
+              config: configPda,
+              capsule: await capsulePdaFor(),
+              creator: spammer.publicKey,
+              treasury: wallet.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .signers([spammer])
+            .rpc();
+          expect.fail("Should have failed");
+        } catch (error) {
+          expect(error.message).to.include("CreationRateLimited");
+        }
+      } finally {
+        await program.methods
+          .setRateLimit(new anchor.BN(86400), 0)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+      }
+    });
+  });
+
+  describe("Locked Capsule Transfer Policy", () => {
+    it("Should reject transferring a locked capsule without a mint when disallowed", async () => {
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.allowLockedTransfer).to.be.true;
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toBuffer("le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Stay Put", "Not transferable while locked", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .setTransferPolicy(false)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .transferCapsule()
+          .accounts({
+            capsule: capsulePda,
+            currentOwner: wallet.publicKey,
+            newOwner: anchor.web3.Keypair.generate().publicKey,
+            treasury: wallet.publicKey,
+            mint: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CannotTransferLockedCapsule");
+      } finally {
+        await program.methods
+          .setTransferPolicy(true)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+      }
+    });
+  });
+
+  describe("Capsule Read Window", () => {
+    let windowCapsulePda: PublicKey;
+    let unlockSoon: number;
+    let relockSoon: number;
+
+    async function nextCapsulePda(): Promise<PublicKey> {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      return capsulePda;
+    }
+
+    it("Should reject a relock date that is not after the unlock date", async () => {
+      const capsulePda = await nextCapsulePda();
+
+      try {
+        await program.methods
+          .createCapsule("Quiz", "Answer: 42", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, true, new anchor.BN(futureUnlockDate), null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: capsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidRelockDate");
+      }
+    });
+
+    it("Should allow views only until the relock date", async () => {
+      windowCapsulePda = await nextCapsulePda();
+      unlockSoon = (await chainNow()) + 5;
+      relockSoon = unlockSoon + 5;
+
+      await program.methods
+        .createCapsule("Quiz", "Answer: 42", new anchor.BN(unlockSoon), [], null, false, { personal: {} }, true, new anchor.BN(relockSoon), null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: windowCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await waitForChainTime(unlockSoon + 1);
+      await program.methods
+        .unlockCapsule()
+        .accounts({
+          capsule: windowCapsulePda,
+          unlocker: wallet.publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .registerView()
+        .accounts({
+          capsule: windowCapsulePda,
+          viewer: wallet.publicKey,
+        })
+        .rpc();
+
+      await waitForChainTime(relockSoon + 1);
+      try {
+        await program.methods
+          .registerView()
+          .accounts({
+            capsule: windowCapsulePda,
+            viewer: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleRelocked");
+      }
+
+      const status = await program.methods
+        .getCapsuleStatus()
+        .accounts({ capsule: windowCapsulePda })
+        .view();
+      expect(status).to.deep.equal({ locked: {} });
+
+      const capsuleAccount = await program.account.capsule.fetch(windowCapsulePda);
+      expect(capsuleAccount.isUnlocked).to.be.true;
+      expect(capsuleAccount.viewCount.toNumber()).to.equal(1);
+    });
+  });
+
+  describe("Capsule Migration", () => {
+    it("Should stamp new capsules with the config version and refuse to migrate them again", async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Current Layout", "Already up to date", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(capsulePda);
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(capsuleAccount.schemaVersion).to.equal(configAccount.version);
+
+      try {
+        await program.methods
+          .migrateCapsule()
+          .accounts({
+            config: configPda,
+            capsule: capsulePda,
+            payer: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyMigrated");
+      }
+    });
+
+    it("Should reject accounts that are not capsules", async () => {
+      try {
+        await program.methods
+          .migrateCapsule()
+          .accounts({
+            config: configPda,
+            capsule: configPda,
+            payer: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("AccountDiscriminatorMismatch");
+      }
+    });
+
+    it("Should refuse to migrate a config that already uses the current layout", async () => {
+      try {
+        await program.methods
+          .migrateConfig()
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("AlreadyMigrated");
+      }
+    });
+  });
+
+  describe("Discard Capsule", () => {
+    let discardCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [discardCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Regret", "Should not have posted this", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: discardCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should refuse to discard without confirmation", async () => {
+      try {
+        await program.methods
+          .discardCapsule(false)
+          .accounts({
+            capsule: discardCapsulePda,
+            owner: wallet.publicKey,
+            creator: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("DiscardNotConfirmed");
+      }
+    });
+
+    it("Should discard a locked capsule and refund its rent", async () => {
+      await program.methods
+        .discardCapsule(true)
+        .accounts({
+          capsule: discardCapsulePda,
+          owner: wallet.publicKey,
+          creator: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleInfo = await provider.connection.getAccountInfo(discardCapsulePda);
+      expect(capsuleInfo).to.be.null;
+    });
+  });
+
+  describe("Capsule Access Hash", () => {
+    const passphrase = "open sesame";
+    let gatedCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [gatedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      const accessHash = Array.from(createHash("sha256").update(passphrase).digest());
+      await program.methods
+        .createCapsule("Shared Link", "For the people with the link", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, accessHash, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: gatedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(gatedCapsulePda);
+      expect(capsuleAccount.accessHash).to.deep.equal(accessHash);
+    });
+
+    it("Should reject a wrong passphrase", async () => {
+      try {
+        await program.methods
+          .verifyAccess("wrong guess")
+          .accounts({
+            capsule: gatedCapsulePda,
+            requester: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidAccessPassphrase");
+      }
+    });
+
+    it("Should grant access with the right passphrase", async () => {
+      await program.methods
+        .verifyAccess(passphrase)
+        .accounts({
+          capsule: gatedCapsulePda,
+          requester: wallet.publicKey,
+        })
+        .rpc();
+    });
+  });
+
+  describe("Ready Announcement", () => {
+    let readyCapsulePda: PublicKey;
+    let unlockSoon: number;
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [readyCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      unlockSoon = (await chainNow()) + 5;
+
+      await program.methods
+        .createCapsule("Soon", "Ready in a few seconds", new anchor.BN(unlockSoon), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: readyCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should refuse to announce before the unlock date", async () => {
+      try {
+        await program.methods
+          .markReady()
+          .accounts({
+            capsule: readyCapsulePda,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleNotReadyToUnlock");
+      }
+    });
+
+    it("Should announce readiness exactly once", async () => {
+      await waitForChainTime(unlockSoon + 1);
+      await program.methods
+        .markReady()
+        .accounts({
+          capsule: readyCapsulePda,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(readyCapsulePda);
+      expect(capsuleAccount.readyAnnounced).to.be.true;
+
+      try {
+        await program.methods
+          .markReady()
+          .accounts({
+            capsule: readyCapsulePda,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ReadyAlreadyAnnounced");
+      }
+    });
+  });
+
+  describe("Two-Step Transfer", () => {
+    let pendingCapsulePda: PublicKey;
+    const pendingOwner = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [pendingCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Hand Over", "Waiting for the new owner", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: pendingCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should fail to accept or cancel without a pending transfer", async () => {
+      try {
+        await program.methods
+          .cancelTransfer()
+          .accounts({
+            capsule: pendingCapsulePda,
+            owner: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingTransfer");
+      }
+
+      try {
+        await program.methods
+          .acceptTransfer()
+          .accounts({
+            capsule: pendingCapsulePda,
+            newOwner: pendingOwner.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([pendingOwner])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NoPendingTransfer");
+      }
+    });
+
+    it("Should let the owner cancel a pending transfer", async () => {
+      await program.methods
+        .initiateTransfer()
+        .accounts({
+          capsule: pendingCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: anchor.web3.Keypair.generate().publicKey,
+        })
+        .rpc();
+
+      await program.methods
+        .cancelTransfer()
+        .accounts({
+          capsule: pendingCapsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(pendingCapsulePda);
+      expect(capsuleAccount.pendingOwner).to.be.null;
+      expect(capsuleAccount.owner.toString()).to.equal(wallet.publicKey.toString());
+    });
+
+    it("Should only move ownership once the pending owner accepts", async () => {
+      await program.methods
+        .initiateTransfer()
+        .accounts({
+          capsule: pendingCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: pendingOwner.publicKey,
+        })
+        .rpc();
+
+      let capsuleAccount = await program.account.capsule.fetch(pendingCapsulePda);
+      expect(capsuleAccount.pendingOwner.toString()).to.equal(pendingOwner.publicKey.toString());
+      expect(capsuleAccount.owner.toString()).to.equal(wallet.publicKey.toString());
+
+      const stranger = anchor.web3.Keypair.generate();
+      try {
+        await program.methods
+          .acceptTransfer()
+          .accounts({
+            capsule: pendingCapsulePda,
+            newOwner: stranger.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NotPendingOwner");
+      }
+
+      await program.methods
+        .acceptTransfer()
+        .accounts({
+          capsule: pendingCapsulePda,
+          newOwner: pendingOwner.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([pendingOwner])
+        .rpc();
+
+      capsuleAccount = await program.account.capsule.fetch(pendingCapsulePda);
+      expect(capsuleAccount.owner.toString()).to.equal(pendingOwner.publicKey.toString());
+      expect(capsuleAccount.pendingOwner).to.be.null;
+      expect(capsuleAccount.transferredAt).to.not.be.null;
+    });
+  });
+
+  describe("Configurable Length Limits", () => {
+    it("Should start from the default limits", async () => {
+      const configAccount = await program.account.config.fetch(configPda);
+      expect(configAccount.maxTitle).to.equal(100);
+      expect(configAccount.maxContent).to.equal(300);
+      expect(configAccount.maxUrl).to.equal(500);
+    });
+
+    it("Should reject limits above the hard maximums", async () => {
+      try {
+        await program.methods
+          .setLimits(201, 300, 500)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidLimit");
+      }
+    });
+
+    it("Should validate titles against the configured limit", async () => {
+      await program.methods
+        .setLimits(10, 300, 500)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      try {
+        const capsuleId = await nextCapsuleId(wallet.publicKey);
+        const [capsulePda] = PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("capsule"),
+            wallet.publicKey.toBuffer(),
+            capsuleId.toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        );
+
+        try {
+          await program.methods
+            .createCapsule("Eleven Chars", "Title is over the lowered limit", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+            .accounts({
+              config: configPda,
+              capsule: capsulePda,
+              creator: wallet.publicKey,
+              treasury: wallet.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+          expect.fail("Should have failed");
+        } catch (error) {
+          expect(error.message).to.include("TitleTooLong");
+        }
+      } finally {
+        await program.methods
+          .setLimits(100, 300, 500)
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+      }
+    });
+  });
+
+  describe("Sealed Content Reveal", () => {
+    let sealedCapsulePda: PublicKey;
+    let unlockSoon: number;
+    const title = "Surprise";
+    const secret = "Happy birthday from the past!";
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [sealedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      unlockSoon = (await chainNow()) + 5;
+
+      const sealedHash = Array.from(createHash("sha256").update(title + secret).digest());
+      await program.methods
+        .createCapsule(title, "", new anchor.BN(unlockSoon), [], null, false, { gift: {} }, false, null, null, null, sealedHash, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: sealedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(sealedCapsulePda);
+      expect(capsuleAccount.sealed).to.be.true;
+      expect(capsuleAccount.content).to.equal("");
+      expect(capsuleAccount.contentHash).to.deep.equal(sealedHash);
+    });
+
+    it("Should refuse to reveal before unlock", async () => {
+      try {
+        await program.methods
+          .revealContent(secret)
+          .accounts({
+            capsule: sealedCapsulePda,
+            owner: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CannotRevealLocked");
+      }
+    });
+
+    it("Should refuse to add URLs while the capsule is sealed", async () => {
+      try {
+        await program.methods
+          .updateCapsule(null, null, null, ["https://example.com/spoiler"], [], false, null, null, null)
+          .accounts({
+            capsule: sealedCapsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("SealedContentProvided");
+      }
+    });
+
+    it("Should reject content that does not match the sealed hash", async () => {
+      await waitForChainTime(unlockSoon + 1);
+      await program.methods
+        .unlockCapsule()
+        .accounts({
+          capsule: sealedCapsulePda,
+          unlocker: wallet.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .revealContent("Not what was promised")
+          .accounts({
+            capsule: sealedCapsulePda,
+            owner: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ContentHashMismatch");
+      }
+    });
+
+    it("Should reveal the content matching the sealed hash", async () => {
+      await program.methods
+        .revealContent(secret)
+        .accounts({
+          capsule: sealedCapsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(sealedCapsulePda);
+      expect(capsuleAccount.sealed).to.be.false;
+      expect(capsuleAccount.content).to.equal(secret);
+    });
+  });
+
+  describe("Capsule Reclaim", () => {
+    let reclaimCapsulePda: PublicKey;
+    const heir = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [reclaimCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Fallback", "Come back if nobody claims me", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: reclaimCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: reclaimCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: heir.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should only let the creator reclaim", async () => {
+      try {
+        await program.methods
+          .reclaimCapsule()
+          .accounts({
+            capsule: reclaimCapsulePda,
+            creator: heir.publicKey,
+          })
+          .signers([heir])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NotCreator");
+      }
+    });
+
+    it("Should refuse to reclaim before the grace period elapses", async () => {
+      try {
+        await program.methods
+          .reclaimCapsule()
+          .accounts({
+            capsule: reclaimCapsulePda,
+            creator: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ReclaimNotYetAllowed");
+      }
+    });
+
+    it("Should return ownership to the creator after the grace period", async () => {
+      await program.methods
+        .setReclaimGracePeriod(new anchor.BN(0))
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .reclaimCapsule()
+          .accounts({
+            capsule: reclaimCapsulePda,
+            creator: wallet.publicKey,
+          })
+          .rpc();
+
+        const capsuleAccount = await program.account.capsule.fetch(reclaimCapsulePda);
+        expect(capsuleAccount.owner.toString()).to.equal(wallet.publicKey.toString());
+      } finally {
+        await program.methods
+          .setReclaimGracePeriod(new anchor.BN(30 * 24 * 60 * 60))
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+      }
+    });
+
+    it("Should refuse to reclaim once the new owner has passed the capsule on", async () => {
+      const airdropTx = await provider.connection.requestAirdrop(heir.publicKey, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdropTx);
+      const nextHolder = anchor.web3.Keypair.generate();
+
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      const [passedOnCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Passed On", "Now belongs to someone else", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: passedOnCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: passedOnCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: heir.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: passedOnCapsulePda,
+          currentOwner: heir.publicKey,
+          newOwner: nextHolder.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([heir])
+        .rpc();
+
+      await program.methods
+        .setReclaimGracePeriod(new anchor.BN(0))
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      try {
+        await program.methods
+          .reclaimCapsule()
+          .accounts({
+            capsule: passedOnCapsulePda,
+            creator: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleTransferredOnward");
+      } finally {
+        await program.methods
+          .setReclaimGracePeriod(new anchor.BN(30 * 24 * 60 * 60))
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+      }
+    });
+  });
+
+  describe("NFT Metadata", () => {
+    let metadataCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = await nextCapsuleId(wallet.publicKey);
+      [metadataCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          capsuleId.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Collectible", "A memory worth minting", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: metadataCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should reject a symbol that is too long", async () => {
+      try {
+        await program.methods
+          .setMetadata("TOOLONGSYMBOL", null)
+          .accounts({
+            capsule: metadataCapsulePda,
+            owner: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("SymbolTooLong");
+      }
+    });
+
+    it("Should set the symbol and URI", async () => {
+      const uri = "https://example.com/capsule.json";
+      await program.methods
+        .setMetadata("DFC", uri)
+        .accounts({
+          capsule: metadataCapsulePda,
+          owner: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(metadataCapsulePda);
+      expect(capsuleAccount.symbol).to.equal("DFC");
+      expect(capsuleAccount.uri).to.equal(uri);
+    });
+  });
+
+  describe("Creator Index", () => {
+    it("Should record newly created capsules in the creator index", async () => {
+      const author = anchor.web3.Keypair.generate();
+      const airdropTx = await provider.connection.requestAirdrop(
+        author.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+
+      const [capsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          author.publicKey.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Indexed", "Easy to find later", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: capsulePda,
+          creator: author.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+
+      const [creatorIndexPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("creator_index"), author.publicKey.toBuffer()],
+        program.programId
+      );
+      const index = await program.account.creatorIndex.fetch(creatorIndexPda);
+      expect(index.creator.toString()).to.equal(author.publicKey.toString());
+      expect(index.capsules.map((key) => key.toString())).to.deep.equal([capsulePda.toString()]);
+      expect(index.head).to.equal(0);
+    });
+  });
+
+  describe("Gift Capsules", () => {
+    const giftRecipient = anchor.web3.Keypair.generate();
+    let giftCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      [giftCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    });
+
+    it("Should reject gifting a capsule to yourself", async () => {
+      try {
+        await program.methods
+          .createGift(wallet.publicKey, "Self Gift", "A present for me", new anchor.BN(futureUnlockDate), [], { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: giftCapsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CannotGiftToSelf");
+      }
+    });
+
+    it("Should create a gift owned by the recipient", async () => {
+      await program.methods
+        .createGift(giftRecipient.publicKey, "Happy Birthday", "Open this on your birthday!", new anchor.BN(futureUnlockDate), [], { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: giftCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(giftCapsulePda);
+      expect(capsuleAccount.creator.toString()).to.equal(wallet.publicKey.toString());
+      expect(capsuleAccount.owner.toString()).to.equal(giftRecipient.publicKey.toString());
+      expect(capsuleAccount.recipient.toString()).to.equal(giftRecipient.publicKey.toString());
+      expect(capsuleAccount.category).to.deep.equal({ gift: {} });
+      expect(capsuleAccount.isUnlocked).to.be.false;
+    });
+
+    it("Should prevent the sender from updating the gift", async () => {
+      try {
+        await program.methods
+          .updateCapsule(null, "Changed my mind", null, [], [], false, null, null, null)
+          .accounts({
+            capsule: giftCapsulePda,
+            updater: wallet.publicKey,
+            mint: null,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NotOwnerOrDelegate");
+      }
+    });
+  });
+
+  describe("Capsule Expiry", () => {
+    const author = anchor.web3.Keypair.generate();
+    let expiringCapsulePda: PublicKey;
+    let unlockAt: number;
+    let expiresAt: number;
+
+    before(async () => {
+      const airdropTx = await provider.connection.requestAirdrop(
+        author.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+
+      [expiringCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          author.publicKey.toBuffer(),
+          new anchor.BN(0).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      unlockAt = (await chainNow()) + 3;
+      expiresAt = unlockAt + 2;
+
+      await program.methods
+        .createCapsule("Ephemeral", "Gone after a while", new anchor.BN(unlockAt), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: expiringCapsulePda,
+          creator: author.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([author])
+        .rpc();
+    });
+
+    it("Should reject an expiry before the unlock date", async () => {
+      try {
+        await program.methods
+          .setExpiry(new anchor.BN(unlockAt - 1))
+          .accounts({
+            capsule: expiringCapsulePda,
+            owner: author.publicKey,
+          })
+          .signers([author])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidExpiry");
+      }
+    });
+
+    it("Should refuse to move the unlock date to or past the expiry", async () => {
+      await program.methods
+        .setExpiry(new anchor.BN(expiresAt))
+        .accounts({
+          capsule: expiringCapsulePda,
+          owner: author.publicKey,
+        })
+        .signers([author])
+        .rpc();
+
+      try {
+        await program.methods
+          .updateCapsule(null, null, new anchor.BN(expiresAt), [], [], false, null, null, null)
+          .accounts({
+            capsule: expiringCapsulePda,
+            updater: author.publicKey,
+            mint: null,
+          })
+          .signers([author])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidExpiry");
+      }
+    });
+
+    it("Should refuse to reap a capsule before it expires", async () => {
+      try {
+        await program.methods
+          .reapExpired()
+          .accounts({
+            capsule: expiringCapsulePda,
+            creator: author.publicKey,
+            reaper: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleNotExpired");
+      }
+    });
+
+    it("Should let anyone reap an expired capsule and refund the creator", async () => {
+      await waitForChainTime(expiresAt + 1);
+      const balanceBefore = await provider.connection.getBalance(author.publicKey);
+
+      await program.methods
+        .reapExpired()
+        .accounts({
+          capsule: expiringCapsulePda,
+          creator: author.publicKey,
+          reaper: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleInfo = await provider.connection.getAccountInfo(expiringCapsulePda);
+      expect(capsuleInfo).to.be.null;
+      const balanceAfter = await provider.connection.getBalance(author.publicKey);
+      expect(balanceAfter).to.be.greaterThan(balanceBefore);
+    });
+  });
+
+  describe("Unlock Grace", () => {
+    let graceCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      [graceCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Grace", "Unlocks a little later", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: graceCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setUnlockGrace(new anchor.BN(0))
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Should reject a negative unlock grace", async () => {
+      try {
+        await program.methods
+          .setUnlockGrace(new anchor.BN(-1))
+          .accounts({
+            config: configPda,
+            authority: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("InvalidUnlockGrace");
+      }
+    });
+
+    it("Should push the countdown back by the unlock grace", async () => {
+      const grace = 30;
+      await program.methods
+        .setUnlockGrace(new anchor.BN(grace))
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+
+      const signature = await program.methods
+        .simulateUnlock()
+        .accounts({
+          config: configPda,
+          capsule: graceCapsulePda,
+          caller: wallet.publicKey,
+        })
+        .rpc({ commitment: "confirmed" });
+      const tx = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const parser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const countdown = [...parser.parseLogs(tx.meta.logMessages)].find((e) => e.name === "unlockCountdown");
+
+      expect(countdown).to.not.be.undefined;
+      expect(countdown.data.secondsRemaining.toNumber()).to.equal(
+        futureUnlockDate + grace - countdown.data.timestamp.toNumber()
+      );
+    });
+  });
+
+  describe("Batch Close", () => {
+    it("Should close unlocked capsules and skip locked ones", async () => {
+      const author = anchor.web3.Keypair.generate();
+      const airdropTx = await provider.connection.requestAirdrop(
+        author.publicKey,
+        LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropTx);
+
+      const capsulePdas = [0, 1].map((id) =>
+        PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("capsule"),
+            author.publicKey.toBuffer(),
+            new anchor.BN(id).toArrayLike(Buffer, "le", 8),
+          ],
+          program.programId
+        )[0]
+      );
+      const unlockSoon = (await chainNow()) + 3;
+      const unlockDates = [unlockSoon, unlockSoon + 3600];
+
+      for (let i = 0; i < capsulePdas.length; i++) {
+        await program.methods
+          .createCapsule(`Batch close ${i}`, "Read me, then tidy up", new anchor.BN(unlockDates[i]), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: capsulePdas[i],
+            creator: author.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([author])
+          .rpc();
+      }
+
+      await waitForChainTime(unlockSoon + 1);
+      await program.methods
+        .unlockCapsule()
+        .accounts({
+          capsule: capsulePdas[0],
+          unlocker: author.publicKey,
+        })
+        .signers([author])
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(author.publicKey);
+      await program.methods
+        .closeCapsulesBatch()
+        .accounts({
+          owner: author.publicKey,
+        })
+        .remainingAccounts(capsulePdas.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })))
+        .signers([author])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(capsulePdas[0])).to.be.null;
+      const lockedCapsule = await program.account.capsule.fetch(capsulePdas[1]);
+      expect(lockedCapsule.isUnlocked).to.be.false;
+      expect(await provider.connection.getBalance(author.publicKey)).to.be.greaterThan(balanceBefore);
+    });
+  });
+
+  describe("Witnessed Capsules", () => {
+    const firstWitness = anchor.web3.Keypair.generate();
+    const secondWitness = anchor.web3.Keypair.generate();
+    let witnessedCapsulePda: PublicKey;
+
+    const witnessAccount = (witness: anchor.web3.Keypair) => ({
+      pubkey: witness.publicKey,
+      isSigner: true,
+      isWritable: false,
+    });
+
+    before(async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      [witnessedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+    });
+
+    it("Should reject the same witness signing twice", async () => {
+      try {
+        await program.methods
+          .createCapsuleWitnessed("Witnessed", "Seen and sealed", new anchor.BN(futureUnlockDate), [], { text: {} })
+          .accounts({
+            config: configPda,
+            capsule: witnessedCapsulePda,
+            creator: wallet.publicKey,
+            treasury: wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .remainingAccounts([witnessAccount(firstWitness), witnessAccount(firstWitness)])
+          .signers([firstWitness])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("DuplicateWitness");
+      }
+    });
+
+    it("Should store every co-signing witness and lock the content", async () => {
+      await program.methods
+        .createCapsuleWitnessed("Witnessed", "Seen and sealed", new anchor.BN(futureUnlockDate), [], { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: witnessedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([witnessAccount(firstWitness)])
+        .signers([firstWitness])
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(witnessedCapsulePda);
+      expect(capsuleAccount.witnesses.map((w) => w.toString())).to.deep.equal([firstWitness.publicKey.toString()]);
+      expect(capsuleAccount.lockedHash).to.be.true;
+    });
+
+    it("Should let the owner add another witness who signs", async () => {
+      await program.methods
+        .addWitness()
+        .accounts({
+          capsule: witnessedCapsulePda,
+          owner: wallet.publicKey,
+          witness: secondWitness.publicKey,
+        })
+        .signers([secondWitness])
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(witnessedCapsulePda);
+      expect(capsuleAccount.witnesses.map((w) => w.toString())).to.deep.equal([
+        firstWitness.publicKey.toString(),
+        secondWitness.publicKey.toString(),
+      ]);
+    });
+
+    it("Should reject adding an existing witness again", async () => {
+      try {
+        await program.methods
+          .addWitness()
+          .accounts({
+            capsule: witnessedCapsulePda,
+            owner: wallet.publicKey,
+            witness: firstWitness.publicKey,
+          })
+          .signers([firstWitness])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("DuplicateWitness");
+      }
+    });
+  });
+
+  describe("Unlock Subscriptions", () => {
+    const subscriber = anchor.web3.Keypair.generate();
+    let subscribedCapsulePda: PublicKey;
+    let subscriptionPda: PublicKey;
+
+    before(async () => {
+      const airdropTx = await provider.connection.requestAirdrop(subscriber.publicKey, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdropTx);
+
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      [subscribedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      [subscriptionPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("subscription"), subscriber.publicKey.toBuffer(), subscribedCapsulePda.toBuffer()],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Remind Me", "Tell my friends when this opens", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: subscribedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should subscribe to a locked capsule and count the subscriber", async () => {
+      await program.methods
+        .subscribe()
+        .accounts({
+          capsule: subscribedCapsulePda,
+          subscription: subscriptionPda,
+          subscriber: subscriber.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([subscriber])
+        .rpc();
+
+      const subscription = await program.account.subscription.fetch(subscriptionPda);
+      expect(subscription.capsule.toString()).to.equal(subscribedCapsulePda.toString());
+      const capsuleAccount = await program.account.capsule.fetch(subscribedCapsulePda);
+      expect(capsuleAccount.subscriberCount.toNumber()).to.equal(1);
+    });
+
+    it("Should reject subscribing twice", async () => {
+      try {
+        await program.methods
+          .subscribe()
+          .accounts({
+            capsule: subscribedCapsulePda,
+            subscription: subscriptionPda,
+            subscriber: subscriber.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([subscriber])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+
+    it("Should refuse to discard a capsule that still has subscribers", async () => {
+      try {
+        await program.methods
+          .discardCapsule(true)
+          .accounts({
+            capsule: subscribedCapsulePda,
+            owner: wallet.publicKey,
+            creator: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("CapsuleHasSubscribers");
+      }
+    });
+
+    it("Should unsubscribe, close the subscription and decrement the count", async () => {
+      await program.methods
+        .unsubscribe()
+        .accounts({
+          capsule: subscribedCapsulePda,
+          subscription: subscriptionPda,
+          subscriber: subscriber.publicKey,
+        })
+        .signers([subscriber])
+        .rpc();
+
+      const subscription = await program.account.subscription.fetchNullable(subscriptionPda);
+      expect(subscription).to.be.null;
+      const capsuleAccount = await program.account.capsule.fetch(subscribedCapsulePda);
+      expect(capsuleAccount.subscriberCount.toNumber()).to.equal(0);
+    });
+  });
+
+  describe("Append Content", () => {
+    let appendCapsulePda: PublicKey;
+
+    before(async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      [appendCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Diary", "Day one.", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: appendCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    });
+
+    it("Should append to the existing content", async () => {
+      await program.methods
+        .appendContent(" Day two.")
+        .accounts({
+          capsule: appendCapsulePda,
+          updater: wallet.publicKey,
+        })
+        .rpc();
+
+      const capsuleAccount = await program.account.capsule.fetch(appendCapsulePda);
+      expect(capsuleAccount.content).to.equal("Day one. Day two.");
+    });
+
+    it("Should reject an append that pushes the content over the limit", async () => {
+      try {
+        await program.methods
+          .appendContent("x".repeat(300))
+          .accounts({
+            capsule: appendCapsulePda,
+            updater: wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("ContentTooLong");
+      }
+
+      const capsuleAccount = await program.account.capsule.fetch(appendCapsulePda);
+      expect(capsuleAccount.content).to.equal("Day one. Day two.");
+    });
+  });
+
+  describe("Close Rent Routing", () => {
+    const holder = anchor.web3.Keypair.generate();
+    let routedCapsulePda: PublicKey;
+
+    before(async () => {
+      const airdropTx = await provider.connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+      await provider.connection.confirmTransaction(airdropTx);
+
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      [routedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const unlockSoon = (await chainNow()) + 3;
+
+      await program.methods
+        .createCapsule("Passed On", "The creator paid for this one", new anchor.BN(unlockSoon), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: routedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: routedCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: holder.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await waitForChainTime(unlockSoon + 1);
+      await program.methods
+        .unlockCapsule()
+        .accounts({
+          capsule: routedCapsulePda,
+          unlocker: holder.publicKey,
+        })
+        .signers([holder])
+        .rpc();
+
+      await program.methods
+        .setCloseRentPolicy(true)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setCloseRentPolicy(false)
+        .accounts({
+          config: configPda,
+          authority: wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    it("Should reject a creator account that did not create the capsule", async () => {
+      try {
+        await program.methods
+          .closeCapsule()
+          .accounts({
+            capsule: routedCapsulePda,
+            owner: holder.publicKey,
+            creator: holder.publicKey,
+          })
+          .signers([holder])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (error) {
+        expect(error.message).to.include("NotCreator");
+      }
+    });
+
+    it("Should refund the rent to the creator instead of the new owner", async () => {
+      const rent = await provider.connection.getBalance(routedCapsulePda);
+      const holderBefore = await provider.connection.getBalance(holder.publicKey);
+      const creatorBefore = await provider.connection.getBalance(wallet.publicKey);
+
+      await program.methods
+        .closeCapsule()
+        .accounts({
+          capsule: routedCapsulePda,
+          owner: holder.publicKey,
+          creator: wallet.publicKey,
+        })
+        .signers([holder])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(routedCapsulePda)).to.be.null;
+      expect(await provider.connection.getBalance(holder.publicKey)).to.equal(holderBefore);
+      // The creator also paid the transaction fee, so only check that most of the rent arrived
+      expect(await provider.connection.getBalance(wallet.publicKey)).to.be.greaterThan(creatorBefore + rent - 10_000);
+    });
+
+    it("Should refund the creator when a new owner discards a locked capsule", async () => {
+      const capsuleId = (await nextCapsuleId(wallet.publicKey)).toNumber();
+      const [lockedCapsulePda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("capsule"),
+          wallet.publicKey.toBuffer(),
+          new anchor.BN(capsuleId).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .createCapsule("Unwanted", "Handed on and thrown away", new anchor.BN(futureUnlockDate), [], null, false, { personal: {} }, false, null, null, null, null, { text: {} })
+        .accounts({
+          config: configPda,
+          capsule: lockedCapsulePda,
+          creator: wallet.publicKey,
+          treasury: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      await program.methods
+        .transferCapsule()
+        .accounts({
+          capsule: lockedCapsulePda,
+          currentOwner: wallet.publicKey,
+          newOwner: holder.publicKey,
+          treasury: wallet.publicKey,
+          mint: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const rent = await provider.connection.getBalance(lockedCapsulePda);
+      const holderBefore = await provider.connection.getBalance(holder.publicKey);
+      const creatorBefore = await provider.connection.getBalance(wallet.publicKey);
+
+      await program.methods
+        .discardCapsule(true)
+        .accounts({
+          capsule: lockedCapsulePda,
+          owner: holder.publicKey,
+          creator: wallet.publicKey,
+        })
+        .signers([holder])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(lockedCapsulePda)).to.be.null;
+      expect(await provider.connection.getBalance(holder.publicKey)).to.equal(holderBefore);
+      expect(await provider.connection.getBalance(wallet.publicKey)).to.be.greaterThan(creatorBefore + rent - 10_000);
+    });
+  });
 });
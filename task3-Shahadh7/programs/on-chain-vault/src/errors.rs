@@ -8,4 +8,64 @@ pub enum VaultError {
     Overflow,
     #[msg("Insufficient balance")]
     InsufficientBalance,
+    #[msg("Daily withdrawal limit exceeded")]
+    DailyLimitExceeded,
+    #[msg("Deposit amount is below the vault minimum")]
+    DepositTooSmall,
+    #[msg("Depositor is not on the vault whitelist")]
+    DepositorNotWhitelisted,
+    #[msg("Depositor whitelist is full")]
+    TooManyDepositors,
+    #[msg("Depositor is already on the vault whitelist")]
+    DepositorAlreadyAllowed,
+    #[msg("Depositor to remove was not found on the whitelist")]
+    DepositorNotFound,
+    #[msg("Vault goal has not been reached yet")]
+    GoalNotReached,
+    #[msg("Vault is time-locked")]
+    VaultTimeLocked,
+    #[msg("Unlock timestamp can only be moved forward")]
+    UnlockTimestampNotLater,
+    #[msg("Vault label is too long")]
+    LabelTooLong,
+    #[msg("Vault label contains control characters")]
+    InvalidLabel,
+    #[msg("Deposit memo is too long")]
+    MemoTooLong,
+    #[msg("Deposit memo contains control characters")]
+    InvalidMemo,
+    #[msg("Withdrawal would leave the vault below the rent-exempt minimum")]
+    WouldBreakRentExemption,
+    #[msg("Too many vault signers")]
+    TooManySigners,
+    #[msg("Signer threshold must be between 1 and the number of signers")]
+    InvalidThreshold,
+    #[msg("Signer list contains duplicates")]
+    DuplicateSigner,
+    #[msg("Caller is not a vault signer")]
+    NotASigner,
+    #[msg("Signer has already approved this withdrawal")]
+    AlreadyApproved,
+    #[msg("Withdrawal proposal does not have enough approvals")]
+    ThresholdNotMet,
+    #[msg("Vault requires an approved withdrawal proposal")]
+    MultisigRequired,
+    #[msg("Vault has no signers set")]
+    MultisigNotEnabled,
+    #[msg("Vault has nothing above the rent-exempt minimum to sweep")]
+    NothingToSweep,
+    #[msg("Deposit fee is above the maximum")]
+    FeeTooHigh,
+    #[msg("Fee recipient account is missing or does not match the vault")]
+    FeeRecipientMismatch,
+    #[msg("Refunds cannot be sent to the vault authority")]
+    RefundToAuthority,
+    #[msg("Withdrawal amount must be greater than zero")]
+    ZeroWithdrawal,
+    #[msg("Refund exceeds what the recipient deposited")]
+    RefundExceedsDeposit,
+    #[msg("New authority must be a real key different from the current one")]
+    InvalidNewAuthority,
+    #[msg("A non-zero fee needs a fee recipient")]
+    InvalidFeeRecipient,
 }
\ No newline at end of file
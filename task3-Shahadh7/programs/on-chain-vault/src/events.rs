@@ -12,6 +12,19 @@ pub struct DepositEvent {
     pub amount: u64,
     pub user: Pubkey,
     pub vault: Pubkey,
+    pub total_deposited: u64,
+    pub new_balance: u64, // vault lamports after the deposit, including rent
+    pub memo: Option<String>,
+    pub last_deposit_at: i64,
+    pub fee: u64, // part of amount sent to the fee recipient instead of the vault
+}
+
+#[event]
+pub struct TokenDepositEvent {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
 }
 
 #[event]
@@ -19,6 +32,8 @@ pub struct WithdrawEvent {
     pub amount: u64,
     pub vault_authority: Pubkey,
     pub vault: Pubkey,
+    pub total_withdrawn: u64,
+    pub new_balance: u64, // vault lamports after the withdrawal, including rent
 }
 
 #[event]
@@ -26,4 +41,141 @@ pub struct ToggleLockEvent {
     pub vault: Pubkey,
     pub vault_authority: Pubkey,
     pub locked: bool,
+}
+
+#[event]
+pub struct VaultLockChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub locked: bool,
+}
+
+#[event]
+pub struct DailyLimitChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub daily_limit: u64,
+}
+
+#[event]
+pub struct MinDepositChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub min_deposit: u64,
+}
+
+#[event]
+pub struct VaultAuthorityTransferred {
+    pub vault: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct DepositorAdded {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event]
+pub struct DepositorRemoved {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub depositor: Pubkey,
+}
+
+#[event]
+pub struct GoalChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub goal_amount: u64,
+}
+
+#[event]
+pub struct GoalReached {
+    pub vault: Pubkey,
+    pub goal_amount: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct RefundIssued {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UnlockTimestampChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub unlock_timestamp: i64,
+}
+
+#[event]
+pub struct VaultLabelChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub label: String,
+}
+
+#[event]
+pub struct VaultSignersChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct WithdrawalProposed {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WithdrawalApproved {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct WithdrawalExecuted {
+    pub vault: Pubkey,
+    pub proposal: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct DustSwept {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+}
+
+#[event]
+pub struct DepositFeeChanged {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub fee_bps: u16,
+    pub fee_recipient: Pubkey,
+}
+
+#[event]
+pub struct MaxWithdrawable {
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+    pub timestamp: i64,
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+//-------------------------------------------------------------------------------
+///
+/// Add depositor instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Add a key to the vault's depositor whitelist (an empty list allows anyone to deposit)
+/// - Only the vault authority should be able to change the whitelist
+/// - Reject duplicates and keep the list within MAX_DEPOSITORS
+/// - Emit a depositor added event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, MAX_DEPOSITORS};
+use crate::errors::VaultError;
+use crate::events::DepositorAdded;
+
+#[derive(Accounts)]
+pub struct AddDepositor<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _add_depositor(ctx: Context<AddDepositor>, depositor: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    if vault.allowed_depositors.contains(&depositor) {
+        return Err(VaultError::DepositorAlreadyAllowed.into());
+    }
+    if vault.allowed_depositors.len() >= MAX_DEPOSITORS {
+        return Err(VaultError::TooManyDepositors.into());
+    }
+
+    vault.allowed_depositors.push(depositor);
+
+    emit!(DepositorAdded {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        depositor,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+//-------------------------------------------------------------------------------
+///
+/// Approve withdrawal instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only vault signers can approve a withdrawal proposal
+/// - Reject a second approval from the same signer
+/// - Record the approval on the proposal
+/// - Emit a withdrawal approved event with the running approval count
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, WithdrawalProposal};
+use crate::errors::VaultError;
+use crate::events::WithdrawalApproved;
+
+#[derive(Accounts)]
+pub struct ApproveWithdrawal<'info> {
+    pub approver: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        has_one = vault,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+}
+
+pub fn _approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    if !ctx.accounts.vault.signers.contains(&approver) {
+        return Err(VaultError::NotASigner.into());
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.approvals.contains(&approver) {
+        return Err(VaultError::AlreadyApproved.into());
+    }
+    proposal.approvals.push(approver);
+
+    emit!(WithdrawalApproved {
+        vault: proposal.vault,
+        proposal: proposal.key(),
+        approver,
+        approvals: ctx.accounts.vault.count_approvals(&proposal.approvals) as u8,
+    });
+
+    Ok(())
+}
@@ -5,17 +5,26 @@
 /// Requirements:
 /// - Verify that the user has enough balance to deposit
 /// - Verify that the vault is not locked
+/// - Reject zero deposits and deposits below the vault minimum
+/// - Reject memos longer than 64 characters or containing control characters
+/// - Only accept whitelisted depositors when the vault has a whitelist
 /// - Transfer lamports from user to vault using CPI (Cross-Program Invocation)
-/// - Emit a deposit event after successful transfer
+/// - When the vault charges a deposit fee, send that share of the amount to the
+///   fee recipient with a second CPI and only the remainder to the vault
+/// - Track the running total of lamports deposited and the last deposit time
+/// - Record each depositor's net SOL deposits, which bound the refunds they can receive
+/// - Emit a deposit event, carrying the optional memo and the resulting vault
+///   balance, after successful transfer
+/// - Mark the vault goal as reached, once, when the balance covers it
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke;
 use anchor_lang::solana_program::system_instruction::transfer;
-use crate::state::Vault;
+use crate::state::{DepositRecord, Vault};
 use crate::errors::VaultError;
-use crate::events::DepositEvent;
+use crate::events::{DepositEvent, GoalReached};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
@@ -24,30 +33,73 @@ pub struct Deposit<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", vault.vault_authority.as_ref()],
+        seeds = [b"vault", vault.seed_authority.as_ref()],
         bump,
         constraint = !vault.locked @ VaultError::VaultLocked,
     )]
     pub vault: Account<'info, Vault>,
 
+    #[account(
+        mut,
+        address = vault.fee_recipient @ VaultError::FeeRecipientMismatch,
+    )]
+    pub fee_recipient: Option<SystemAccount<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + DepositRecord::INIT_SPACE,
+        seeds = [b"deposit", vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn _deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+pub fn _deposit(ctx: Context<Deposit>, amount: u64, memo: Option<String>) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
 
     if vault.locked  {
         return Err(VaultError::VaultLocked.into());
     }
 
+    if !vault.can_deposit(&ctx.accounts.user.key()) {
+        return Err(VaultError::DepositorNotWhitelisted.into());
+    }
+
+    if amount == 0 || amount < vault.min_deposit {
+        return Err(VaultError::DepositTooSmall.into());
+    }
+
+    if let Some(memo) = &memo {
+        Vault::validate_memo(memo)?;
+    }
+
     if ctx.accounts.user.get_lamports() < amount {
         return Err(VaultError::InsufficientBalance.into());
     }
 
+    let fee = vault.deposit_fee(amount);
+    let net_amount = amount - fee;
+    let total_deposited = vault.total_deposited.checked_add(net_amount).ok_or(VaultError::Overflow)?;
+
+    if fee > 0 {
+        let fee_recipient = ctx.accounts.fee_recipient.as_ref().ok_or(VaultError::FeeRecipientMismatch)?;
+        invoke(
+            &transfer(&ctx.accounts.user.key(), &fee_recipient.key(), fee),
+            &[
+                ctx.accounts.user.to_account_info(),
+                fee_recipient.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
     let transfer_instruction = transfer(
         &ctx.accounts.user.key(),
         &vault.key(),
-        amount,
+        net_amount,
     );
 
     invoke(
@@ -59,11 +111,35 @@ pub fn _deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         ],
     )?;
 
+    vault.total_deposited = total_deposited;
+    vault.last_deposit_at = Clock::get()?.unix_timestamp;
+
+    let deposit_record = &mut ctx.accounts.deposit_record;
+    deposit_record.vault = vault.key();
+    deposit_record.depositor = ctx.accounts.user.key();
+    deposit_record.deposited = deposit_record.deposited.checked_add(net_amount).ok_or(VaultError::Overflow)?;
+    deposit_record.bump = ctx.bumps.deposit_record;
+
     emit!(DepositEvent {
         vault: vault.key(),
         user: ctx.accounts.user.key(),
         amount,
+        total_deposited,
+        last_deposit_at: vault.last_deposit_at,
+        new_balance: vault.to_account_info().lamports(),
+        memo,
+        fee,
     });
 
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+    let balance = vault.get_lamports().saturating_sub(rent_exempt_minimum);
+    if vault.check_goal(balance) {
+        emit!(GoalReached {
+            vault: vault.key(),
+            goal_amount: vault.goal_amount,
+            balance,
+        });
+    }
+
     Ok(())
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+//-------------------------------------------------------------------------------
+///
+/// SPL token deposit instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Verify that the vault is not locked
+/// - Only accept whitelisted depositors when the vault has a whitelist
+/// - Verify that the user has enough tokens to deposit
+/// - Transfer tokens from the user's token account to the vault's associated
+///   token account using a token program CPI
+/// - Emit a token deposit event after successful transfer
+/// 
+/// NOTE: The vault's associated token account for the mint must already exist.
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked};
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::TokenDepositEvent;
+
+#[derive(Accounts)]
+pub struct DepositSpl<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump,
+        constraint = !vault.locked @ VaultError::VaultLocked,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vault,
+    )]
+    pub vault_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn _deposit_spl(ctx: Context<DepositSpl>, amount: u64) -> Result<()> {
+    if ctx.accounts.vault.locked {
+        return Err(VaultError::VaultLocked.into());
+    }
+
+    if !ctx.accounts.vault.can_deposit(&ctx.accounts.user.key()) {
+        return Err(VaultError::DepositorNotWhitelisted.into());
+    }
+
+    if ctx.accounts.user_token_account.amount < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    emit!(TokenDepositEvent {
+        vault: ctx.accounts.vault.key(),
+        mint: ctx.accounts.mint.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+//-------------------------------------------------------------------------------
+///
+/// Execute withdrawal instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only vault signers can execute a withdrawal proposal
+/// - Verify that the vault is not locked, the goal is reached and the unlock
+///   timestamp has passed, as for a regular withdrawal
+/// - Only count approvals from keys that are still vault signers, and require
+///   at least the vault threshold
+/// - Reject withdrawals that would leave the vault below the rent-exempt minimum
+/// - Enforce the rolling daily withdrawal limit (0 means no limit)
+/// - Transfer lamports from the vault to the proposal recipient
+/// - Close the proposal and return its rent to the proposer
+/// - Emit a withdrawal executed event after successful transfer
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, WithdrawalProposal, WITHDRAW_WINDOW_SECONDS};
+use crate::errors::VaultError;
+use crate::events::WithdrawalExecuted;
+
+#[derive(Accounts)]
+pub struct ExecuteWithdrawal<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        has_one = vault,
+        has_one = proposer,
+        has_one = recipient,
+        close = proposer,
+        seeds = [b"proposal", vault.key().as_ref(), proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+    /// CHECK: Only receives the proposal rent; checked against the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+}
+
+pub fn _execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    if !vault.signers.contains(&ctx.accounts.executor.key()) {
+        return Err(VaultError::NotASigner.into());
+    }
+    if vault.locked {
+        return Err(VaultError::VaultLocked.into());
+    }
+    if vault.goal_pending() {
+        return Err(VaultError::GoalNotReached.into());
+    }
+    let clock = Clock::get()?;
+    if clock.unix_timestamp < vault.unlock_timestamp {
+        msg!("Vault is time-locked for another {} seconds", vault.unlock_timestamp - clock.unix_timestamp);
+        return Err(VaultError::VaultTimeLocked.into());
+    }
+    let approvals = vault.count_approvals(&ctx.accounts.proposal.approvals);
+    if approvals < vault.threshold as usize {
+        return Err(VaultError::ThresholdNotMet.into());
+    }
+
+    let amount = ctx.accounts.proposal.amount;
+    let balance = vault.get_lamports();
+    if balance < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+    let available = balance.saturating_sub(rent_exempt_minimum);
+    if available < amount {
+        msg!("Withdrawal would break rent exemption; at most {} lamports can be withdrawn", available);
+        return Err(VaultError::WouldBreakRentExemption.into());
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    if clock.unix_timestamp.saturating_sub(vault.window_start) >= WITHDRAW_WINDOW_SECONDS {
+        vault.window_start = clock.unix_timestamp;
+        vault.withdrawn_today = 0;
+    }
+    let withdrawn_today = vault.withdrawn_today.checked_add(amount).ok_or(VaultError::Overflow)?;
+    if vault.daily_limit > 0 && withdrawn_today > vault.daily_limit {
+        return Err(VaultError::DailyLimitExceeded.into());
+    }
+    vault.withdrawn_today = withdrawn_today;
+    vault.total_withdrawn = vault.total_withdrawn.checked_add(amount).ok_or(VaultError::Overflow)?;
+    vault.last_withdraw_at = clock.unix_timestamp;
+
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+    emit!(WithdrawalExecuted {
+        vault: ctx.accounts.vault.key(),
+        proposal: ctx.accounts.proposal.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        approvals: approvals as u8,
+    });
+    Ok(())
+}
@@ -35,11 +35,33 @@ pub struct InitializeVault<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn _init_vault(ctx: Context<InitializeVault>, locked: bool) -> Result<()> {
+pub fn _init_vault(ctx: Context<InitializeVault>, locked: bool, label: String) -> Result<()> {
+  Vault::validate_label(&label)?;
+
   let vault = &mut ctx.accounts.vault;
 
   vault.vault_authority = ctx.accounts.vault_authority.key();
+  vault.seed_authority = ctx.accounts.vault_authority.key();
   vault.locked = locked;
+  vault.daily_limit = 0;
+  vault.withdrawn_today = 0;
+  vault.window_start = 0;
+  vault.min_deposit = 0;
+  vault.total_deposited = 0;
+  vault.total_withdrawn = 0;
+  vault.allowed_depositors = Vec::new();
+  vault.goal_amount = 0;
+  vault.goal_reached = false;
+  vault.unlock_timestamp = 0;
+  vault.label = label;
+  vault.signers = Vec::new();
+  vault.threshold = 0;
+  vault.proposal_count = 0;
+  vault.created_at = Clock::get()?.unix_timestamp;
+  vault.last_deposit_at = 0;
+  vault.last_withdraw_at = 0;
+  vault.fee_bps = 0;
+  vault.fee_recipient = Pubkey::default();
 
   emit!(InitializeVaultEvent {
     vault: vault.key(),
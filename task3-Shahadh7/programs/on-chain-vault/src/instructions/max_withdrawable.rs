@@ -0,0 +1,62 @@
+//-------------------------------------------------------------------------------
+///
+/// Max withdrawable query for the on-chain vault
+/// 
+/// Requirements:
+/// - Anyone can query any vault; no state is modified
+/// - Report 0 while the vault is locked, has signers, has a pending goal or
+///   has not reached its unlock timestamp
+/// - Otherwise report the balance above the rent-exempt minimum, capped by
+///   what is left of the rolling daily limit (0 means no limit)
+/// - Emit a max withdrawable event with the computed amount
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, WITHDRAW_WINDOW_SECONDS};
+use crate::events::MaxWithdrawable;
+
+#[derive(Accounts)]
+pub struct QueryMaxWithdrawable<'info> {
+    #[account(
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _max_withdrawable(ctx: Context<QueryMaxWithdrawable>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    let balance = vault.get_lamports();
+
+    let blocked = vault.locked
+        || vault.multisig_enabled()
+        || vault.goal_pending()
+        || clock.unix_timestamp < vault.unlock_timestamp;
+    let amount = if blocked {
+        0
+    } else {
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(vault.to_account_info().data_len());
+        let available = balance.saturating_sub(rent_exempt_minimum);
+        if vault.daily_limit > 0 {
+            // An expired window resets on the next withdrawal, so the full limit is available again
+            let withdrawn_today = if clock.unix_timestamp.saturating_sub(vault.window_start) >= WITHDRAW_WINDOW_SECONDS {
+                0
+            } else {
+                vault.withdrawn_today
+            };
+            available.min(vault.daily_limit.saturating_sub(withdrawn_today))
+        } else {
+            available
+        }
+    };
+
+    emit!(MaxWithdrawable {
+        vault: vault.key(),
+        amount,
+        balance,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(())
+}
@@ -2,8 +2,44 @@ mod initialize;
 mod deposit;
 mod withdraw;
 mod toggle_lock;
+mod set_lock;
+mod deposit_spl;
+mod set_daily_limit;
+mod set_min_deposit;
+mod transfer_vault_authority;
+mod add_depositor;
+mod remove_depositor;
+mod set_goal;
+mod refund;
+mod set_unlock_timestamp;
+mod set_label;
+mod set_signers;
+mod propose_withdrawal;
+mod approve_withdrawal;
+mod execute_withdrawal;
+mod sweep_dust;
+mod set_deposit_fee;
+mod max_withdrawable;
 
 pub use initialize::*;
 pub use deposit::*;
 pub use withdraw::*;
-pub use toggle_lock::*;
\ No newline at end of file
+pub use toggle_lock::*;
+pub use set_lock::*;
+pub use deposit_spl::*;
+pub use set_daily_limit::*;
+pub use set_min_deposit::*;
+pub use transfer_vault_authority::*;
+pub use add_depositor::*;
+pub use remove_depositor::*;
+pub use set_goal::*;
+pub use refund::*;
+pub use set_unlock_timestamp::*;
+pub use set_label::*;
+pub use set_signers::*;
+pub use propose_withdrawal::*;
+pub use approve_withdrawal::*;
+pub use execute_withdrawal::*;
+pub use sweep_dust::*;
+pub use set_deposit_fee::*;
+pub use max_withdrawable::*;
\ No newline at end of file
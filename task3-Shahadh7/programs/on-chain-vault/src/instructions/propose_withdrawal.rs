@@ -0,0 +1,72 @@
+//-------------------------------------------------------------------------------
+///
+/// Propose withdrawal instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only vault signers can propose a withdrawal
+/// - Reject proposals on vaults without signers and zero amounts
+/// - Initialize a withdrawal proposal PDA numbered by the vault's proposal count
+/// - Count the proposer's own approval
+/// - Emit a withdrawal proposed event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, WithdrawalProposal};
+use crate::errors::VaultError;
+use crate::events::WithdrawalProposed;
+
+#[derive(Accounts)]
+pub struct ProposeWithdrawal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + WithdrawalProposal::INIT_SPACE,
+        seeds = [b"proposal", vault.key().as_ref(), vault.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, WithdrawalProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _propose_withdrawal(ctx: Context<ProposeWithdrawal>, amount: u64, recipient: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    if !vault.multisig_enabled() {
+        return Err(VaultError::MultisigNotEnabled.into());
+    }
+    if !vault.signers.contains(&ctx.accounts.proposer.key()) {
+        return Err(VaultError::NotASigner.into());
+    }
+    if amount == 0 {
+        return Err(VaultError::ZeroWithdrawal.into());
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.vault = vault.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.recipient = recipient;
+    proposal.amount = amount;
+    proposal.id = vault.proposal_count;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.bump = ctx.bumps.proposal;
+
+    vault.proposal_count = vault.proposal_count.checked_add(1).ok_or(VaultError::Overflow)?;
+
+    emit!(WithdrawalProposed {
+        vault: vault.key(),
+        proposal: proposal.key(),
+        proposer: proposal.proposer,
+        recipient,
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//-------------------------------------------------------------------------------
+///
+/// Refund instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only the vault authority can send a refund
+/// - Refunds are allowed before the vault goal is reached, unlike withdrawals
+/// - Refunds do not count against the daily withdrawal limit
+/// - Refunds are rejected once vault signers are set
+/// - Verify that the vault has enough balance above rent-exemption to refund
+/// - Only refund recorded depositors, and never more than they deposited; refunds
+///   skip the goal, unlock timestamp and daily limit checks, so anything beyond a
+///   depositor's own funds has to go through withdraw instead
+/// - Reject the vault authority as the recipient
+/// - Transfer lamports from the vault to the named recipient
+/// - Emit a refund issued event after successful transfer
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{DepositRecord, Vault};
+use crate::errors::VaultError;
+use crate::events::RefundIssued;
+
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    #[account(
+        mut,
+        seeds = [b"deposit", vault.key().as_ref(), recipient.key().as_ref()],
+        bump = deposit_record.bump
+    )]
+    pub deposit_record: Account<'info, DepositRecord>,
+}
+
+pub fn _refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+    if ctx.accounts.vault.locked {
+        return Err(VaultError::VaultLocked.into());
+    }
+    if ctx.accounts.vault.multisig_enabled() {
+        return Err(VaultError::MultisigRequired.into());
+    }
+    if ctx.accounts.recipient.key() == ctx.accounts.vault_authority.key() {
+        return Err(VaultError::RefundToAuthority.into());
+    }
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ctx.accounts.vault.to_account_info().data_len());
+    let available = ctx.accounts.vault.get_lamports().saturating_sub(rent_exempt_minimum);
+    if available < amount {
+        return Err(VaultError::InsufficientBalance.into());
+    }
+    let deposit_record = &mut ctx.accounts.deposit_record;
+    let refundable = deposit_record.deposited.saturating_sub(deposit_record.refunded);
+    if amount > refundable {
+        msg!("Recipient can be refunded at most {} more lamports", refundable);
+        return Err(VaultError::RefundExceedsDeposit.into());
+    }
+    deposit_record.refunded = deposit_record.refunded.checked_add(amount).ok_or(VaultError::Overflow)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.total_withdrawn = vault.total_withdrawn.checked_add(amount).ok_or(VaultError::Overflow)?;
+    vault.last_withdraw_at = Clock::get()?.unix_timestamp;
+
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+    emit!(RefundIssued {
+        vault: ctx.accounts.vault.key(),
+        vault_authority: ctx.accounts.vault_authority.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+    });
+    Ok(())
+}
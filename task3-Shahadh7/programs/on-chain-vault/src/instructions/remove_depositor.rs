@@ -0,0 +1,46 @@
+//-------------------------------------------------------------------------------
+///
+/// Remove depositor instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Remove a key from the vault's depositor whitelist
+/// - Only the vault authority should be able to change the whitelist
+/// - Emptying the list reopens the vault to deposits from anyone
+/// - Emit a depositor removed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::DepositorRemoved;
+
+#[derive(Accounts)]
+pub struct RemoveDepositor<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _remove_depositor(ctx: Context<RemoveDepositor>, depositor: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    let index = match vault.allowed_depositors.iter().position(|key| *key == depositor) {
+        Some(index) => index,
+        None => return Err(VaultError::DepositorNotFound.into()),
+    };
+    vault.allowed_depositors.remove(index);
+
+    emit!(DepositorRemoved {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        depositor,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+///
+/// Set daily limit instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Set the rolling daily withdrawal limit of the vault (0 disables the limit)
+/// - Only the vault authority should be able to set the limit
+/// - Emit a daily limit changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::DailyLimitChanged;
+
+#[derive(Accounts)]
+pub struct SetDailyLimit<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_daily_limit(ctx: Context<SetDailyLimit>, daily_limit: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.daily_limit = daily_limit;
+
+    emit!(DailyLimitChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        daily_limit,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//-------------------------------------------------------------------------------
+///
+/// Set deposit fee instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only the vault authority should be able to set the deposit fee
+/// - Reject fees above MAX_FEE_BPS
+/// - Reject the default pubkey as the fee recipient while the fee is non-zero
+/// - Store the fee in basis points together with the account that receives it
+/// - Emit a deposit fee changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, MAX_FEE_BPS};
+use crate::errors::VaultError;
+use crate::events::DepositFeeChanged;
+
+#[derive(Accounts)]
+pub struct SetDepositFee<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_deposit_fee(ctx: Context<SetDepositFee>, fee_bps: u16, fee_recipient: Pubkey) -> Result<()> {
+    if fee_bps > MAX_FEE_BPS {
+        return Err(VaultError::FeeTooHigh.into());
+    }
+    if fee_bps > 0 && fee_recipient == Pubkey::default() {
+        return Err(VaultError::InvalidFeeRecipient.into());
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.fee_bps = fee_bps;
+    vault.fee_recipient = fee_recipient;
+
+    emit!(DepositFeeChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        fee_bps,
+        fee_recipient,
+    });
+
+    Ok(())
+}
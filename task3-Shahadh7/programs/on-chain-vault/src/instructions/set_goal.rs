@@ -0,0 +1,52 @@
+//-------------------------------------------------------------------------------
+///
+/// Set goal instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Set the lamport goal the vault must reach before withdrawals (0 disables the goal)
+/// - Only the vault authority should be able to set the goal
+/// - Re-evaluate the goal against the current balance and emit a goal reached event if met
+/// - Emit a goal changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::{GoalChanged, GoalReached};
+
+#[derive(Accounts)]
+pub struct SetGoal<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_goal(ctx: Context<SetGoal>, goal_amount: u64) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ctx.accounts.vault.to_account_info().data_len());
+    let balance = ctx.accounts.vault.get_lamports().saturating_sub(rent_exempt_minimum);
+    let vault = &mut ctx.accounts.vault;
+
+    vault.goal_amount = goal_amount;
+    vault.goal_reached = false;
+
+    emit!(GoalChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        goal_amount,
+    });
+
+    if vault.check_goal(balance) {
+        emit!(GoalReached {
+            vault: vault.key(),
+            goal_amount,
+            balance,
+        });
+    }
+
+    Ok(())
+}
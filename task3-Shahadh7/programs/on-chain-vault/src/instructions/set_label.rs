@@ -0,0 +1,42 @@
+//-------------------------------------------------------------------------------
+///
+/// Set label instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Set the human-readable label shown by UIs (metadata only)
+/// - Only the vault authority should be able to set the label
+/// - Reject labels that are too long or contain control characters
+/// - Emit a vault label changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::VaultLabelChanged;
+
+#[derive(Accounts)]
+pub struct SetLabel<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_label(ctx: Context<SetLabel>, label: String) -> Result<()> {
+    Vault::validate_label(&label)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.label = label;
+
+    emit!(VaultLabelChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        label: vault.label.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+///
+/// Set lock instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Set the locked state of the vault to an explicit value
+/// - Only the vault authority should be able to set the lock
+/// - Emit a vault lock changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::VaultLockChanged;
+
+#[derive(Accounts)]
+pub struct SetLock<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_lock(ctx: Context<SetLock>, locked: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.locked = locked;
+
+    emit!(VaultLockChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        locked,
+    });
+
+    Ok(())
+}
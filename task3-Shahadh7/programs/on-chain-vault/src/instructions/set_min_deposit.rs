@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+///
+/// Set minimum deposit instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Set the minimum lamports accepted by a single deposit (0 only rejects empty deposits)
+/// - Only the vault authority should be able to set the minimum
+/// - Emit a minimum deposit changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::MinDepositChanged;
+
+#[derive(Accounts)]
+pub struct SetMinDeposit<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_min_deposit(ctx: Context<SetMinDeposit>, min_deposit: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    vault.min_deposit = min_deposit;
+
+    emit!(MinDepositChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        min_deposit,
+    });
+
+    Ok(())
+}
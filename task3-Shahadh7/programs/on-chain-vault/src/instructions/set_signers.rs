@@ -0,0 +1,76 @@
+//-------------------------------------------------------------------------------
+///
+/// Set signers instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only the vault authority should be able to set the signers
+/// - Once signers are set, changing them also needs threshold approval from the
+///   current signers, who co-sign and are passed as remaining accounts
+/// - An empty signer list with a zero threshold disables multi-signer withdrawals
+/// - Otherwise the threshold must be between 1 and the number of signers
+/// - Reject duplicate signers and lists longer than the maximum
+/// - Emit a vault signers changed event after the state change
+/// 
+/// NOTE: While signers are set, funds only leave the vault through an approved
+/// withdrawal proposal.
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, MAX_SIGNERS};
+use crate::errors::VaultError;
+use crate::events::VaultSignersChanged;
+
+#[derive(Accounts)]
+pub struct SetSigners<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_signers(ctx: Context<SetSigners>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+    // Otherwise the authority could clear the list, or make itself the only signer, and bypass approvals
+    if ctx.accounts.vault.multisig_enabled() {
+        let mut approvals = vec![ctx.accounts.vault_authority.key()];
+        for account in ctx.remaining_accounts.iter().filter(|account| account.is_signer) {
+            if !approvals.contains(account.key) {
+                approvals.push(*account.key);
+            }
+        }
+        if ctx.accounts.vault.count_approvals(&approvals) < ctx.accounts.vault.threshold as usize {
+            return Err(VaultError::ThresholdNotMet.into());
+        }
+    }
+    if signers.len() > MAX_SIGNERS {
+        return Err(VaultError::TooManySigners.into());
+    }
+    if signers.iter().enumerate().any(|(i, signer)| signers[..i].contains(signer)) {
+        return Err(VaultError::DuplicateSigner.into());
+    }
+    let valid_threshold = if signers.is_empty() {
+        threshold == 0
+    } else {
+        threshold > 0 && threshold as usize <= signers.len()
+    };
+    if !valid_threshold {
+        return Err(VaultError::InvalidThreshold.into());
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    vault.signers = signers;
+    vault.threshold = threshold;
+
+    emit!(VaultSignersChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        signers: vault.signers.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//-------------------------------------------------------------------------------
+///
+/// Set unlock timestamp instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Set the time before which withdrawals are rejected (deposits stay open)
+/// - Only the vault authority should be able to set the timestamp
+/// - The timestamp can only be moved forward in time
+/// - Emit an unlock timestamp changed event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::UnlockTimestampChanged;
+
+#[derive(Accounts)]
+pub struct SetUnlockTimestamp<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_unlock_timestamp(ctx: Context<SetUnlockTimestamp>, unlock_timestamp: i64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    if unlock_timestamp <= vault.unlock_timestamp {
+        return Err(VaultError::UnlockTimestampNotLater.into());
+    }
+
+    vault.unlock_timestamp = unlock_timestamp;
+
+    emit!(UnlockTimestampChanged {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        unlock_timestamp,
+    });
+
+    Ok(())
+}
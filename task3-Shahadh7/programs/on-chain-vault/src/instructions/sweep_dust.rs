@@ -0,0 +1,81 @@
+//-------------------------------------------------------------------------------
+///
+/// Sweep dust instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only the vault authority can sweep the vault
+/// - The same checks as a regular withdrawal apply: the vault must be unlocked,
+///   without signers, past its goal and unlock timestamp, and within the daily limit
+/// - Always sweep everything above the rent-exempt minimum, never a chosen amount
+/// - Reject the sweep when there is nothing above the rent-exempt minimum
+/// - Transfer lamports from the vault to the treasury account
+/// - Track the running total of lamports withdrawn and the last withdrawal time
+/// - Emit a dust swept event after successful transfer
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Vault, WITHDRAW_WINDOW_SECONDS};
+use crate::errors::VaultError;
+use crate::events::DustSwept;
+
+#[derive(Accounts)]
+pub struct SweepDust<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub treasury: SystemAccount<'info>,
+}
+
+pub fn _sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+    if ctx.accounts.vault.locked {
+        return Err(VaultError::VaultLocked.into());
+    }
+    if ctx.accounts.vault.multisig_enabled() {
+        return Err(VaultError::MultisigRequired.into());
+    }
+    if ctx.accounts.vault.goal_pending() {
+        return Err(VaultError::GoalNotReached.into());
+    }
+    let clock = Clock::get()?;
+    if clock.unix_timestamp < ctx.accounts.vault.unlock_timestamp {
+        msg!("Vault is time-locked for another {} seconds", ctx.accounts.vault.unlock_timestamp - clock.unix_timestamp);
+        return Err(VaultError::VaultTimeLocked.into());
+    }
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ctx.accounts.vault.to_account_info().data_len());
+    let amount = ctx.accounts.vault.get_lamports().saturating_sub(rent_exempt_minimum);
+    if amount == 0 {
+        return Err(VaultError::NothingToSweep.into());
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    if clock.unix_timestamp.saturating_sub(vault.window_start) >= WITHDRAW_WINDOW_SECONDS {
+        vault.window_start = clock.unix_timestamp;
+        vault.withdrawn_today = 0;
+    }
+    let withdrawn_today = vault.withdrawn_today.checked_add(amount).ok_or(VaultError::Overflow)?;
+    if vault.daily_limit > 0 && withdrawn_today > vault.daily_limit {
+        return Err(VaultError::DailyLimitExceeded.into());
+    }
+    let total_withdrawn = vault.total_withdrawn.checked_add(amount).ok_or(VaultError::Overflow)?;
+    vault.withdrawn_today = withdrawn_today;
+    vault.total_withdrawn = total_withdrawn;
+    vault.last_withdraw_at = clock.unix_timestamp;
+
+    **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += amount;
+    emit!(DustSwept {
+        vault: ctx.accounts.vault.key(),
+        vault_authority: ctx.accounts.vault_authority.key(),
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+        total_withdrawn,
+    });
+    Ok(())
+}
@@ -19,7 +19,7 @@ pub struct ToggleLock<'info> {
     #[account(
         mut,
         has_one = vault_authority,
-        seeds = [b"vault", vault_authority.key().as_ref()],
+        seeds = [b"vault", vault.seed_authority.as_ref()],
         bump
     )]
     pub vault: Account<'info, Vault>,
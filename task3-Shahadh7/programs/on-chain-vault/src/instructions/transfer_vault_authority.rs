@@ -0,0 +1,48 @@
+//-------------------------------------------------------------------------------
+///
+/// Transfer vault authority instruction for the on-chain vault
+/// 
+/// Requirements:
+/// - Only the current vault authority should be able to transfer the vault
+/// - Reject the default pubkey and the current authority as the new authority
+/// - Store the new authority on the vault; the vault PDA address does not change,
+///   it stays derived from the original seed authority
+/// - Emit a vault authority transferred event after the state change
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::VaultAuthorityTransferred;
+
+#[derive(Accounts)]
+pub struct TransferVaultAuthority<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _transfer_vault_authority(ctx: Context<TransferVaultAuthority>, new_authority: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+
+    if new_authority == Pubkey::default() || new_authority == vault.vault_authority {
+        return Err(VaultError::InvalidNewAuthority.into());
+    }
+
+    let old_authority = vault.vault_authority;
+    vault.vault_authority = new_authority;
+
+    emit!(VaultAuthorityTransferred {
+        vault: vault.key(),
+        old_authority,
+        new_authority,
+    });
+
+    Ok(())
+}
@@ -3,15 +3,22 @@
 /// TASK: Implement the withdraw functionality for the on-chain vault
 /// 
 /// Requirements:
+/// - Verify that the caller is the vault authority
 /// - Verify that the vault is not locked
-/// - Verify that the vault has enough balance to withdraw
+/// - Reject direct withdrawals once vault signers are set (use a proposal instead)
+/// - Block withdrawals until the vault goal is reached (refunds are separate)
+/// - Block withdrawals until the vault unlock timestamp has passed
+/// - Verify that the vault has enough balance to withdraw, and reject withdrawals
+///   that would leave it below the rent-exempt minimum
+/// - Enforce the rolling daily withdrawal limit (0 means no limit)
 /// - Transfer lamports from vault to vault authority
-/// - Emit a withdraw event after successful transfer
+/// - Track the running total of lamports withdrawn and the last withdrawal time
+/// - Emit a withdraw event with the resulting vault balance after successful transfer
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use crate::state::Vault;
+use crate::state::{Vault, WITHDRAW_WINDOW_SECONDS};
 use crate::errors::VaultError;
 use crate::events::WithdrawEvent;
 
@@ -21,7 +28,8 @@ pub struct Withdraw<'info> {
     pub vault_authority: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"vault", vault_authority.key().as_ref()],
+        has_one = vault_authority,
+        seeds = [b"vault", vault.seed_authority.as_ref()],
         bump
     )]
     pub vault: Account<'info, Vault>,
@@ -33,15 +41,50 @@ pub fn _withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
     if ctx.accounts.vault.locked {
         return Err(VaultError::VaultLocked.into());
     }
-    if ctx.accounts.vault.get_lamports() < amount {
+    if ctx.accounts.vault.multisig_enabled() {
+        return Err(VaultError::MultisigRequired.into());
+    }
+    if ctx.accounts.vault.goal_pending() {
+        return Err(VaultError::GoalNotReached.into());
+    }
+    let clock = Clock::get()?;
+    if clock.unix_timestamp < ctx.accounts.vault.unlock_timestamp {
+        msg!("Vault is time-locked for another {} seconds", ctx.accounts.vault.unlock_timestamp - clock.unix_timestamp);
+        return Err(VaultError::VaultTimeLocked.into());
+    }
+    let balance = ctx.accounts.vault.get_lamports();
+    if balance < amount {
         return Err(VaultError::InsufficientBalance.into());
     }
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ctx.accounts.vault.to_account_info().data_len());
+    let available = balance.saturating_sub(rent_exempt_minimum);
+    if available < amount {
+        msg!("Withdrawal would break rent exemption; at most {} lamports can be withdrawn", available);
+        return Err(VaultError::WouldBreakRentExemption.into());
+    }
+
+    let vault = &mut ctx.accounts.vault;
+    if clock.unix_timestamp.saturating_sub(vault.window_start) >= WITHDRAW_WINDOW_SECONDS {
+        vault.window_start = clock.unix_timestamp;
+        vault.withdrawn_today = 0;
+    }
+    let withdrawn_today = vault.withdrawn_today.checked_add(amount).ok_or(VaultError::Overflow)?;
+    if vault.daily_limit > 0 && withdrawn_today > vault.daily_limit {
+        return Err(VaultError::DailyLimitExceeded.into());
+    }
+    let total_withdrawn = vault.total_withdrawn.checked_add(amount).ok_or(VaultError::Overflow)?;
+    vault.withdrawn_today = withdrawn_today;
+    vault.total_withdrawn = total_withdrawn;
+    vault.last_withdraw_at = clock.unix_timestamp;
+
     **ctx.accounts.vault.to_account_info().try_borrow_mut_lamports()? -= amount;
     **ctx.accounts.vault_authority.to_account_info().try_borrow_mut_lamports()? += amount;
     emit!(WithdrawEvent {
         vault: ctx.accounts.vault.key(),
         vault_authority: ctx.accounts.vault_authority.key(),
         amount,
+        total_withdrawn,
+        new_balance: ctx.accounts.vault.to_account_info().lamports(),
     });
     Ok(())
 }
\ No newline at end of file
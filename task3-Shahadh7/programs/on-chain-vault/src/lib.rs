@@ -37,12 +37,16 @@ declare_id!("ARmiAGe6oAEq5BKguHydD3zt2n5PkV2Q5PLA1McuMkJT");
 pub mod on_chain_vault {
     use super::*;
 
-    pub fn init_vault(ctx: Context<InitializeVault>, locked: bool) -> Result<()> {
-      _init_vault(ctx, locked)
+    pub fn init_vault(ctx: Context<InitializeVault>, locked: bool, label: String) -> Result<()> {
+      _init_vault(ctx, locked, label)
     }
 
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-      _deposit(ctx, amount)
+    pub fn deposit(ctx: Context<Deposit>, amount: u64, memo: Option<String>) -> Result<()> {
+      _deposit(ctx, amount, memo)
+    }
+
+    pub fn deposit_spl(ctx: Context<DepositSpl>, amount: u64) -> Result<()> {
+      _deposit_spl(ctx, amount)
     }
 
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
@@ -52,4 +56,72 @@ pub mod on_chain_vault {
     pub fn toggle_lock(ctx: Context<ToggleLock>) -> Result<()> {
       _toggle_lock(ctx)
     }
+
+    pub fn set_lock(ctx: Context<SetLock>, locked: bool) -> Result<()> {
+      _set_lock(ctx, locked)
+    }
+
+    pub fn set_daily_limit(ctx: Context<SetDailyLimit>, daily_limit: u64) -> Result<()> {
+      _set_daily_limit(ctx, daily_limit)
+    }
+
+    pub fn set_min_deposit(ctx: Context<SetMinDeposit>, min_deposit: u64) -> Result<()> {
+      _set_min_deposit(ctx, min_deposit)
+    }
+
+    pub fn transfer_vault_authority(ctx: Context<TransferVaultAuthority>, new_authority: Pubkey) -> Result<()> {
+      _transfer_vault_authority(ctx, new_authority)
+    }
+
+    pub fn add_depositor(ctx: Context<AddDepositor>, depositor: Pubkey) -> Result<()> {
+      _add_depositor(ctx, depositor)
+    }
+
+    pub fn remove_depositor(ctx: Context<RemoveDepositor>, depositor: Pubkey) -> Result<()> {
+      _remove_depositor(ctx, depositor)
+    }
+
+    pub fn set_goal(ctx: Context<SetGoal>, goal_amount: u64) -> Result<()> {
+      _set_goal(ctx, goal_amount)
+    }
+
+    pub fn refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+      _refund(ctx, amount)
+    }
+
+    pub fn set_unlock_timestamp(ctx: Context<SetUnlockTimestamp>, unlock_timestamp: i64) -> Result<()> {
+      _set_unlock_timestamp(ctx, unlock_timestamp)
+    }
+
+    pub fn set_label(ctx: Context<SetLabel>, label: String) -> Result<()> {
+      _set_label(ctx, label)
+    }
+
+    pub fn set_signers(ctx: Context<SetSigners>, signers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+      _set_signers(ctx, signers, threshold)
+    }
+
+    pub fn propose_withdrawal(ctx: Context<ProposeWithdrawal>, amount: u64, recipient: Pubkey) -> Result<()> {
+      _propose_withdrawal(ctx, amount, recipient)
+    }
+
+    pub fn approve_withdrawal(ctx: Context<ApproveWithdrawal>) -> Result<()> {
+      _approve_withdrawal(ctx)
+    }
+
+    pub fn execute_withdrawal(ctx: Context<ExecuteWithdrawal>) -> Result<()> {
+      _execute_withdrawal(ctx)
+    }
+
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
+      _sweep_dust(ctx)
+    }
+
+    pub fn set_deposit_fee(ctx: Context<SetDepositFee>, fee_bps: u16, fee_recipient: Pubkey) -> Result<()> {
+      _set_deposit_fee(ctx, fee_bps, fee_recipient)
+    }
+
+    pub fn max_withdrawable(ctx: Context<QueryMaxWithdrawable>) -> Result<()> {
+      _max_withdrawable(ctx)
+    }
 }
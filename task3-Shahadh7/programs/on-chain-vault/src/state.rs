@@ -1,8 +1,116 @@
 use anchor_lang::prelude::*;
+use crate::errors::VaultError;
+
+pub const WITHDRAW_WINDOW_SECONDS: i64 = 86_400;
+pub const MAX_DEPOSITORS: usize = 20;
+pub const MAX_LABEL_LENGTH: usize = 32;
+pub const MAX_MEMO_LENGTH: usize = 64;
+pub const MAX_SIGNERS: usize = 10;
+pub const MAX_FEE_BPS: u16 = 1_000; // 10% of each deposit
 
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
     pub vault_authority: Pubkey,
+    pub seed_authority: Pubkey, // key the vault PDA was derived from
     pub locked: bool,
+    pub daily_limit: u64, // 0 means no limit
+    pub withdrawn_today: u64,
+    pub window_start: i64,
+    pub min_deposit: u64,
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    #[max_len(MAX_DEPOSITORS)]
+    pub allowed_depositors: Vec<Pubkey>, // empty means anyone may deposit
+    pub goal_amount: u64, // 0 means no goal
+    pub goal_reached: bool,
+    pub unlock_timestamp: i64, // withdrawals are rejected before this time
+    #[max_len(MAX_LABEL_LENGTH)]
+    pub label: String, // display name for UIs, not used by deposit/withdraw
+    #[max_len(MAX_SIGNERS)]
+    pub signers: Vec<Pubkey>, // empty means the vault authority withdraws directly
+    pub threshold: u8, // approvals needed to execute a withdrawal proposal
+    pub proposal_count: u64, // id of the next withdrawal proposal
+    pub created_at: i64,
+    pub last_deposit_at: i64, // 0 until the first SOL deposit
+    pub last_withdraw_at: i64, // 0 until lamports first leave the vault
+    pub fee_bps: u16, // share of each SOL deposit sent to fee_recipient, in basis points
+    pub fee_recipient: Pubkey,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DepositRecord {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub deposited: u64, // net SOL deposited, after the deposit fee
+    pub refunded: u64, // refunds may never exceed deposited
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalProposal {
+    pub vault: Pubkey,
+    pub proposer: Pubkey, // receives the proposal rent back on execution
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub id: u64,
+    #[max_len(MAX_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Vault {
+    pub fn can_deposit(&self, user: &Pubkey) -> bool {
+        self.allowed_depositors.is_empty() || self.allowed_depositors.contains(user)
+    }
+
+    // Marks the goal as reached once the balance covers it; returns true only the first time
+    pub fn check_goal(&mut self, balance: u64) -> bool {
+        if self.goal_amount == 0 || self.goal_reached || balance < self.goal_amount {
+            return false;
+        }
+        self.goal_reached = true;
+        true
+    }
+
+    pub fn goal_pending(&self) -> bool {
+        self.goal_amount > 0 && !self.goal_reached
+    }
+
+    pub fn multisig_enabled(&self) -> bool {
+        !self.signers.is_empty()
+    }
+
+    // Approvals from keys that were removed from the signer list no longer count
+    pub fn count_approvals(&self, approvals: &[Pubkey]) -> usize {
+        approvals.iter().filter(|approver| self.signers.contains(approver)).count()
+    }
+
+    pub fn deposit_fee(&self, amount: u64) -> u64 {
+        // fee_bps is capped well below 10_000, so the result always fits back into a u64
+        (amount as u128 * self.fee_bps as u128 / 10_000) as u64
+    }
+
+    pub fn validate_label(label: &str) -> Result<()> {
+        if label.len() > MAX_LABEL_LENGTH {
+            return Err(VaultError::LabelTooLong.into());
+        }
+        if label.chars().any(char::is_control) {
+            return Err(VaultError::InvalidLabel.into());
+        }
+        Ok(())
+    }
+
+    // Memos are only emitted in the deposit event, never stored on the vault
+    pub fn validate_memo(memo: &str) -> Result<()> {
+        if memo.len() > MAX_MEMO_LENGTH {
+            return Err(VaultError::MemoTooLong.into());
+        }
+        if memo.chars().any(char::is_control) {
+            return Err(VaultError::InvalidMemo.into());
+        }
+        Ok(())
+    }
 }
@@ -30,7 +30,7 @@ describe("on-chain-vault", async () => {
 
     const locked = false;
 
-    let txSig = await program.methods.initVault(locked).accounts({
+    let txSig = await program.methods.initVault(locked, "").accounts({
       vaultAuthority: alice.publicKey,
       vault: vaultAlicePDA,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -39,6 +39,9 @@ describe("on-chain-vault", async () => {
     const vaultData = await program.account.vault.fetch(vaultAlicePDA);
     assert.strictEqual(vaultData.vaultAuthority.toString(), alice.publicKey.toString(), "Vault authority should be Alice's public key");
     assert.strictEqual(vaultData.locked, false, "Vault should be unlocked");
+    assert.isAbove(vaultData.createdAt.toNumber(), 0, "Vault creation time should be recorded");
+    assert.strictEqual(vaultData.lastDepositAt.toNumber(), 0, "Vault should have no deposits yet");
+    assert.strictEqual(vaultData.lastWithdrawAt.toNumber(), 0, "Vault should have no withdrawals yet");
 
     // Check event was emitted
     const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
@@ -62,7 +65,7 @@ describe("on-chain-vault", async () => {
 
     const locked = true;
 
-    let txSig = await program.methods.initVault(locked).accounts({
+    let txSig = await program.methods.initVault(locked, "").accounts({
       vaultAuthority: bob.publicKey,
       vault: vaultBobPDA,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -94,7 +97,7 @@ describe("on-chain-vault", async () => {
 
     const locked = false;
 
-    let txSig = await program.methods.initVault(locked).accounts({
+    let txSig = await program.methods.initVault(locked, "").accounts({
       vaultAuthority: anatoly.publicKey,
       vault: vaultAnatolyPDA,
       systemProgram: anchor.web3.SystemProgram.programId,
@@ -126,7 +129,7 @@ describe("on-chain-vault", async () => {
 
     let flag = "This should fail";
     try {
-      await program.methods.initVault(locked).accounts({
+      await program.methods.initVault(locked, "").accounts({
         vaultAuthority: alice.publicKey,
         vault: vaultAlicePDA,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -148,7 +151,7 @@ describe("on-chain-vault", async () => {
     let flag = "This should fail";
     try {
       // Alice trying to initialize a vault for Bob (but Alice signs)
-      await program.methods.initVault(locked).accounts({
+      await program.methods.initVault(locked, "").accounts({
         vaultAuthority: bob.publicKey,
         vault: vaultBobPDA,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -166,9 +169,10 @@ describe("on-chain-vault", async () => {
     const vaultBalanceBefore = await provider.connection.getBalance(vaultAlicePDA);
     const userBalanceBefore = await provider.connection.getBalance(alice.publicKey);
 
-    let txSig = await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+    let txSig = await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
       user: alice.publicKey,
       vault: vaultAlicePDA,
+      feeRecipient: null,
     }).signers([alice]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultAlicePDA);
@@ -190,9 +194,13 @@ describe("on-chain-vault", async () => {
         assert.strictEqual(event.data.amount.toString(), depositAmount.toString(), "Event amount should match deposit amount");
         assert.strictEqual(event.data.user.toString(), alice.publicKey.toString(), "Event user should be Alice");
         assert.strictEqual(event.data.vault.toString(), vaultAlicePDA.toString(), "Event vault should be Alice's vault");
+        assert.strictEqual(event.data.totalDeposited.toString(), depositAmount.toString(), "Event total deposited should include this deposit");
+        assert.strictEqual(event.data.newBalance.toString(), vaultBalanceAfter.toString(), "Event new balance should match the vault balance");
       }
     }
     assert.isTrue(logsEmitted, "DepositEvent should have been emitted");
+    const vaultData = await program.account.vault.fetch(vaultAlicePDA);
+    assert.strictEqual(vaultData.totalDeposited.toString(), depositAmount.toString(), "Vault total deposited should be updated");
   });
 
   it("Cannot deposit to locked vault (Bob's vault)", async () => {
@@ -200,9 +208,10 @@ describe("on-chain-vault", async () => {
 
     let flag = "This should fail";
     try {
-      await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+      await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
         user: bob.publicKey,
         vault: vaultBobPDA,
+        feeRecipient: null,
       }).signers([bob]).rpc({ commitment: "confirmed" });
     } catch (error) {
       flag = "Failed";
@@ -221,9 +230,10 @@ describe("on-chain-vault", async () => {
 
     let flag = "This should fail";
     try {
-      await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+      await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
         user: charlie.publicKey,
         vault: charlieVaultPDA,
+        feeRecipient: null,
       }).signers([charlie]).rpc({ commitment: "confirmed" });
     } catch (error) {
       flag = "Failed";
@@ -238,9 +248,10 @@ describe("on-chain-vault", async () => {
     
     let flag = "This should fail";
     try {
-      await program.methods.deposit(depositAmount).accounts({
+      await program.methods.deposit(depositAmount, null).accounts({
         user: alice.publicKey,
         vault: vaultAlicePDA,
+        feeRecipient: null,
       }).signers([alice]).rpc({ commitment: "confirmed" });
     } catch (error) {
       flag = "Failed";
@@ -280,9 +291,10 @@ describe("on-chain-vault", async () => {
     const depositAmount = 500000;
     const vaultBalanceBefore = await provider.connection.getBalance(vaultBobPDA);
 
-    await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+    await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
       user: bob.publicKey,
       vault: vaultBobPDA,
+      feeRecipient: null,
     }).signers([bob]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultBobPDA);
@@ -320,9 +332,10 @@ describe("on-chain-vault", async () => {
 
     let flag = "This should fail";
     try {
-      await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+      await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
         user: alice.publicKey,
         vault: vaultAlicePDA,
+        feeRecipient: null,
       }).signers([alice]).rpc({ commitment: "confirmed" });
     } catch (error) {
       flag = "Failed";
@@ -361,9 +374,13 @@ describe("on-chain-vault", async () => {
         assert.strictEqual(event.data.amount.toString(), withdrawAmount.toString(), "Event amount should match withdrawal amount");
         assert.strictEqual(event.data.vaultAuthority.toString(), bob.publicKey.toString(), "Event vault authority should be Bob");
         assert.strictEqual(event.data.vault.toString(), vaultBobPDA.toString(), "Event vault should be Bob's vault");
+        assert.strictEqual(event.data.totalWithdrawn.toString(), withdrawAmount.toString(), "Event total withdrawn should include this withdrawal");
+        assert.strictEqual(event.data.newBalance.toString(), vaultBalanceAfter.toString(), "Event new balance should match the vault balance");
       }
     }
     assert.isTrue(logsEmitted, "WithdrawEvent should have been emitted");
+    const vaultData = await program.account.vault.fetch(vaultBobPDA);
+    assert.strictEqual(vaultData.totalWithdrawn.toString(), withdrawAmount.toString(), "Vault total withdrawn should be updated");
   });
 
   it("Cannot withdraw from Alice's locked vault", async () => {
@@ -417,6 +434,26 @@ describe("on-chain-vault", async () => {
     assert.strictEqual(flag, "Failed", "Withdrawing more than vault balance should fail");
   });
 
+  it("Cannot withdraw into the vault's rent-exempt reserve", async () => {
+    const vaultInfo = await provider.connection.getAccountInfo(vaultBobPDA);
+    const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(vaultInfo.data.length);
+    const overdrawAmount = vaultInfo.lamports - rentExemptMinimum + 1;
+
+    let flag = "This should fail";
+    try {
+      await program.methods.withdraw(new anchor.BN(overdrawAmount)).accounts({
+        vaultAuthority: bob.publicKey,
+        vault: vaultBobPDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "WouldBreakRentExemption", "Should fail with WouldBreakRentExemption error");
+      assert.isTrue(error.logs.some((log: string) => log.includes(`at most ${overdrawAmount - 1} lamports`)), "Should log the max withdrawable amount");
+    }
+    assert.strictEqual(flag, "Failed", "Withdrawing into the rent-exempt reserve should fail");
+  });
+
   it("Cannot toggle lock without authority", async () => {
     let flag = "This should fail";
     try {
@@ -451,6 +488,52 @@ describe("on-chain-vault", async () => {
     assert.strictEqual(flag, "Failed", "Toggling lock on non-existent vault should fail");
   });
 
+  it("Set lock explicitly on Anatoly's vault and back", async () => {
+    let txSig = await program.methods.setLock(true).accounts({
+      vaultAuthority: anatoly.publicKey,
+      vault: vaultAnatolyPDA,
+    }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+    let vaultData = await program.account.vault.fetch(vaultAnatolyPDA);
+    assert.strictEqual(vaultData.locked, true, "Vault should be locked");
+
+    // Check event was emitted
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "vaultLockChanged") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.locked, true, "Event locked status should be true");
+      }
+    }
+    assert.isTrue(logsEmitted, "VaultLockChanged should have been emitted");
+
+    await program.methods.setLock(false).accounts({
+      vaultAuthority: anatoly.publicKey,
+      vault: vaultAnatolyPDA,
+    }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+    vaultData = await program.account.vault.fetch(vaultAnatolyPDA);
+    assert.strictEqual(vaultData.locked, false, "Vault should be unlocked");
+  });
+
+  it("Cannot set lock without authority", async () => {
+    let flag = "This should fail";
+    try {
+      await program.methods.setLock(true).accounts({
+        vaultAuthority: alice.publicKey, // Alice trying to lock Anatoly's vault
+        vault: vaultAnatolyPDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      assert.isTrue(error.toString().includes("Error"), "Should fail due to seeds constraint - wrong authority");
+    }
+    assert.strictEqual(flag, "Failed", "Setting lock without proper authority should fail");
+  });
+
   it("Unlock Alice's vault and withdraw", async () => {
     // First unlock
     await program.methods.toggleLock().accounts({
@@ -478,9 +561,10 @@ describe("on-chain-vault", async () => {
     const depositAmount = 2000000;
     const vaultBalanceBefore = await provider.connection.getBalance(vaultAnatolyPDA);
 
-    await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+    await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
       user: anatoly.publicKey,
       vault: vaultAnatolyPDA,
+      feeRecipient: null,
     }).signers([anatoly]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultAnatolyPDA);
@@ -489,14 +573,16 @@ describe("on-chain-vault", async () => {
 
   it("Multiple deposits and withdrawals work correctly", async () => {
     // Multiple deposits
-    await program.methods.deposit(new anchor.BN(100000)).accounts({
+    await program.methods.deposit(new anchor.BN(100000), null).accounts({
       user: anatoly.publicKey,
       vault: vaultAnatolyPDA,
+      feeRecipient: null,
     }).signers([anatoly]).rpc({ commitment: "confirmed" });
 
-    await program.methods.deposit(new anchor.BN(200000)).accounts({
+    await program.methods.deposit(new anchor.BN(200000), null).accounts({
       user: anatoly.publicKey,
       vault: vaultAnatolyPDA,
+      feeRecipient: null,
     }).signers([anatoly]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultAnatolyPDA);
@@ -511,14 +597,76 @@ describe("on-chain-vault", async () => {
     assert.isTrue(vaultBalanceFinal < vaultBalanceAfter, "Vault balance should decrease after withdrawal");
   });
 
+  it("Daily withdrawal limit blocks withdrawals past the cap", async () => {
+    // Anatoly already withdrew in this window, so a limit of 1 lamport is exhausted
+    await program.methods.setDailyLimit(new anchor.BN(1)).accounts({
+      vaultAuthority: anatoly.publicKey,
+      vault: vaultAnatolyPDA,
+    }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+    const vaultData = await program.account.vault.fetch(vaultAnatolyPDA);
+    assert.strictEqual(vaultData.dailyLimit.toString(), "1", "Daily limit should be set");
+
+    let flag = "This should fail";
+    try {
+      await program.methods.withdraw(new anchor.BN(1)).accounts({
+        vaultAuthority: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "DailyLimitExceeded", "Should fail with DailyLimitExceeded error");
+    }
+    assert.strictEqual(flag, "Failed", "Withdrawing past the daily limit should fail");
+
+    // Remove the limit again
+    await program.methods.setDailyLimit(new anchor.BN(0)).accounts({
+      vaultAuthority: anatoly.publicKey,
+      vault: vaultAnatolyPDA,
+    }).signers([anatoly]).rpc({ commitment: "confirmed" });
+  });
+
+  it("Deposits of zero or below the vault minimum are rejected", async () => {
+    await program.methods.setMinDeposit(new anchor.BN(10000)).accounts({
+      vaultAuthority: anatoly.publicKey,
+      vault: vaultAnatolyPDA,
+    }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+    const vaultData = await program.account.vault.fetch(vaultAnatolyPDA);
+    assert.strictEqual(vaultData.minDeposit.toString(), "10000", "Minimum deposit should be set");
+
+    for (const amount of [0, 9999]) {
+      let flag = "This should fail";
+      try {
+        await program.methods.deposit(new anchor.BN(amount), null).accounts({
+          user: anatoly.publicKey,
+          vault: vaultAnatolyPDA,
+          feeRecipient: null,
+        }).signers([anatoly]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "DepositTooSmall", "Should fail with DepositTooSmall error");
+      }
+      assert.strictEqual(flag, "Failed", `Depositing ${amount} lamports should fail`);
+    }
+
+    await program.methods.setMinDeposit(new anchor.BN(0)).accounts({
+      vaultAuthority: anatoly.publicKey,
+      vault: vaultAnatolyPDA,
+    }).signers([anatoly]).rpc({ commitment: "confirmed" });
+  });
+
   it("Cross-user deposits: Alice deposits into Bob's vault", async () => {
     const depositAmount = 500000;
     const vaultBalanceBefore = await provider.connection.getBalance(vaultBobPDA);
     const aliceBalanceBefore = await provider.connection.getBalance(alice.publicKey);
 
-    let txSig = await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+    let txSig = await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
       user: alice.publicKey,
       vault: vaultBobPDA,
+      feeRecipient: null,
     }).signers([alice]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultBobPDA);
@@ -550,9 +698,10 @@ describe("on-chain-vault", async () => {
     const vaultBalanceBefore = await provider.connection.getBalance(vaultAnatolyPDA);
     const bobBalanceBefore = await provider.connection.getBalance(bob.publicKey);
 
-    let txSig = await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+    let txSig = await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
       user: bob.publicKey,
       vault: vaultAnatolyPDA,
+      feeRecipient: null,
     }).signers([bob]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultAnatolyPDA);
@@ -584,9 +733,10 @@ describe("on-chain-vault", async () => {
     const vaultBalanceBefore = await provider.connection.getBalance(vaultAlicePDA);
     const anatolyBalanceBefore = await provider.connection.getBalance(anatoly.publicKey);
 
-    let txSig = await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+    let txSig = await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
       user: anatoly.publicKey,
       vault: vaultAlicePDA,
+      feeRecipient: null,
     }).signers([anatoly]).rpc({ commitment: "confirmed" });
 
     const vaultBalanceAfter = await provider.connection.getBalance(vaultAlicePDA);
@@ -624,9 +774,10 @@ describe("on-chain-vault", async () => {
 
     let flag = "This should fail";
     try {
-      await program.methods.deposit(new anchor.BN(depositAmount)).accounts({
+      await program.methods.deposit(new anchor.BN(depositAmount), null).accounts({
         user: bob.publicKey, // Bob trying to deposit
         vault: vaultAlicePDA, // Into Alice's locked vault
+        feeRecipient: null,
       }).signers([bob]).rpc({ commitment: "confirmed" });
     } catch (error) {
       flag = "Failed";
@@ -644,9 +795,10 @@ describe("on-chain-vault", async () => {
     }).signers([alice]).rpc({ commitment: "confirmed" });
 
     // Bob deposits into Alice's vault
-    await program.methods.deposit(new anchor.BN(200000)).accounts({
+    await program.methods.deposit(new anchor.BN(200000), null).accounts({
       user: bob.publicKey,
       vault: vaultAlicePDA,
+      feeRecipient: null,
     }).signers([bob]).rpc({ commitment: "confirmed" });
 
     // Bob tries to withdraw from Alice's vault (should fail)
@@ -670,6 +822,869 @@ describe("on-chain-vault", async () => {
       vault: vaultAlicePDA,
     }).signers([alice]).rpc({ commitment: "confirmed" });
   });
+
+  it("Transfer vault authority keeps the PDA and hands over control", async () => {
+    const dave = anchor.web3.Keypair.generate();
+    const eve = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, dave.publicKey);
+    await airdrop(provider.connection, eve.publicKey);
+    const [vaultDavePDA] = getVaultPDA(dave.publicKey);
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: dave.publicKey,
+      vault: vaultDavePDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([dave]).rpc({ commitment: "confirmed" });
+
+    for (const invalidAuthority of [anchor.web3.PublicKey.default, dave.publicKey]) {
+      let flag = "This should fail";
+      try {
+        await program.methods.transferVaultAuthority(invalidAuthority).accounts({
+          vaultAuthority: dave.publicKey,
+          vault: vaultDavePDA,
+        }).signers([dave]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidNewAuthority", "Should fail with InvalidNewAuthority error");
+      }
+      assert.strictEqual(flag, "Failed", "Transferring to the default key or the current authority should be rejected");
+    }
+
+    let txSig = await program.methods.transferVaultAuthority(eve.publicKey).accounts({
+      vaultAuthority: dave.publicKey,
+      vault: vaultDavePDA,
+    }).signers([dave]).rpc({ commitment: "confirmed" });
+
+    const vaultData = await program.account.vault.fetch(vaultDavePDA);
+    assert.strictEqual(vaultData.vaultAuthority.toString(), eve.publicKey.toString(), "Vault authority should be Eve");
+    assert.strictEqual(vaultData.seedAuthority.toString(), dave.publicKey.toString(), "Seed authority should still be Dave");
+
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "vaultAuthorityTransferred") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.vault.toString(), vaultDavePDA.toString(), "Event vault should be Dave's vault");
+        assert.strictEqual(event.data.oldAuthority.toString(), dave.publicKey.toString(), "Event old authority should be Dave");
+        assert.strictEqual(event.data.newAuthority.toString(), eve.publicKey.toString(), "Event new authority should be Eve");
+      }
+    }
+    assert.isTrue(logsEmitted, "VaultAuthorityTransferred should have been emitted");
+
+    await program.methods.deposit(new anchor.BN(500000), null).accounts({
+      user: dave.publicKey,
+      vault: vaultDavePDA,
+      feeRecipient: null,
+    }).signers([dave]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: dave.publicKey,
+        vault: vaultDavePDA,
+      }).signers([dave]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Should fail with ConstraintHasOne error");
+    }
+    assert.strictEqual(flag, "Failed", "Previous authority should not be able to withdraw");
+
+    await program.methods.withdraw(new anchor.BN(100000)).accounts({
+      vaultAuthority: eve.publicKey,
+      vault: vaultDavePDA,
+    }).signers([eve]).rpc({ commitment: "confirmed" });
+  });
+
+  it("Depositor whitelist restricts who can deposit", async () => {
+    const frank = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, frank.publicKey);
+    const [vaultFrankPDA] = getVaultPDA(frank.publicKey);
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: frank.publicKey,
+      vault: vaultFrankPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([frank]).rpc({ commitment: "confirmed" });
+
+    // Empty whitelist: anyone can deposit
+    await program.methods.deposit(new anchor.BN(100000), null).accounts({
+      user: bob.publicKey,
+      vault: vaultFrankPDA,
+      feeRecipient: null,
+    }).signers([bob]).rpc({ commitment: "confirmed" });
+
+    await program.methods.addDepositor(alice.publicKey).accounts({
+      vaultAuthority: frank.publicKey,
+      vault: vaultFrankPDA,
+    }).signers([frank]).rpc({ commitment: "confirmed" });
+
+    let vaultData = await program.account.vault.fetch(vaultFrankPDA);
+    assert.deepEqual(vaultData.allowedDepositors.map((k) => k.toString()), [alice.publicKey.toString()], "Alice should be whitelisted");
+
+    let flag = "This should fail";
+    try {
+      await program.methods.deposit(new anchor.BN(100000), null).accounts({
+        user: bob.publicKey,
+        vault: vaultFrankPDA,
+        feeRecipient: null,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "DepositorNotWhitelisted", "Should fail with DepositorNotWhitelisted error");
+    }
+    assert.strictEqual(flag, "Failed", "Non-whitelisted user should not be able to deposit");
+
+    await program.methods.deposit(new anchor.BN(100000), null).accounts({
+      user: alice.publicKey,
+      vault: vaultFrankPDA,
+      feeRecipient: null,
+    }).signers([alice]).rpc({ commitment: "confirmed" });
+
+    flag = "This should fail";
+    try {
+      await program.methods.addDepositor(bob.publicKey).accounts({
+        vaultAuthority: bob.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+    }
+    assert.strictEqual(flag, "Failed", "Only the vault authority should be able to change the whitelist");
+
+    await program.methods.removeDepositor(alice.publicKey).accounts({
+      vaultAuthority: frank.publicKey,
+      vault: vaultFrankPDA,
+    }).signers([frank]).rpc({ commitment: "confirmed" });
+
+    vaultData = await program.account.vault.fetch(vaultFrankPDA);
+    assert.strictEqual(vaultData.allowedDepositors.length, 0, "Whitelist should be empty again");
+
+    flag = "This should fail";
+    try {
+      await program.methods.removeDepositor(alice.publicKey).accounts({
+        vaultAuthority: frank.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([frank]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "DepositorNotFound", "Should fail with DepositorNotFound error");
+    }
+    assert.strictEqual(flag, "Failed", "Removing an unknown depositor should fail");
+  });
+
+  it("Goal vault blocks withdrawals until the goal is reached but allows refunds", async () => {
+    const grace = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, grace.publicKey);
+    const [vaultGracePDA] = getVaultPDA(grace.publicKey);
+    const goal = anchor.web3.LAMPORTS_PER_SOL;
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: grace.publicKey,
+      vault: vaultGracePDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([grace]).rpc({ commitment: "confirmed" });
+
+    await program.methods.setGoal(new anchor.BN(goal)).accounts({
+      vaultAuthority: grace.publicKey,
+      vault: vaultGracePDA,
+    }).signers([grace]).rpc({ commitment: "confirmed" });
+
+    await program.methods.deposit(new anchor.BN(goal / 2), null).accounts({
+      user: bob.publicKey,
+      vault: vaultGracePDA,
+      feeRecipient: null,
+    }).signers([bob]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: grace.publicKey,
+        vault: vaultGracePDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "GoalNotReached", "Should fail with GoalNotReached error");
+    }
+    assert.strictEqual(flag, "Failed", "Withdrawing before the goal is reached should fail");
+
+    // Refunds are allowed before the goal is reached
+    const bobBalanceBefore = await provider.connection.getBalance(bob.publicKey);
+    await program.methods.refund(new anchor.BN(100000)).accounts({
+      vaultAuthority: grace.publicKey,
+      vault: vaultGracePDA,
+      recipient: bob.publicKey,
+    }).signers([grace]).rpc({ commitment: "confirmed" });
+    const bobBalanceAfter = await provider.connection.getBalance(bob.publicKey);
+    assert.strictEqual(bobBalanceAfter - bobBalanceBefore, 100000, "Bob should receive the refund");
+
+    let txSig = await program.methods.deposit(new anchor.BN(goal), null).accounts({
+      user: alice.publicKey,
+      vault: vaultGracePDA,
+      feeRecipient: null,
+    }).signers([alice]).rpc({ commitment: "confirmed" });
+
+    const vaultData = await program.account.vault.fetch(vaultGracePDA);
+    assert.isTrue(vaultData.goalReached, "Goal should be reached");
+
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "goalReached") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.goalAmount.toString(), goal.toString(), "Event goal amount should match");
+      }
+    }
+    assert.isTrue(logsEmitted, "GoalReached should have been emitted");
+
+    await program.methods.withdraw(new anchor.BN(100000)).accounts({
+      vaultAuthority: grace.publicKey,
+      vault: vaultGracePDA,
+    }).signers([grace]).rpc({ commitment: "confirmed" });
+  });
+
+  it("Time-locked vault rejects withdrawals but still accepts deposits", async () => {
+    const heidi = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, heidi.publicKey);
+    const [vaultHeidiPDA] = getVaultPDA(heidi.publicKey);
+    const unlockTimestamp = Math.floor(Date.now() / 1000) + 3600;
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: heidi.publicKey,
+      vault: vaultHeidiPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([heidi]).rpc({ commitment: "confirmed" });
+
+    await program.methods.setUnlockTimestamp(new anchor.BN(unlockTimestamp)).accounts({
+      vaultAuthority: heidi.publicKey,
+      vault: vaultHeidiPDA,
+    }).signers([heidi]).rpc({ commitment: "confirmed" });
+
+    await program.methods.deposit(new anchor.BN(500000), null).accounts({
+      user: heidi.publicKey,
+      vault: vaultHeidiPDA,
+      feeRecipient: null,
+    }).signers([heidi]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: heidi.publicKey,
+        vault: vaultHeidiPDA,
+      }).signers([heidi]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "VaultTimeLocked", "Should fail with VaultTimeLocked error");
+    }
+    assert.strictEqual(flag, "Failed", "Withdrawing before the unlock timestamp should fail");
+
+    flag = "This should fail";
+    try {
+      await program.methods.setUnlockTimestamp(new anchor.BN(unlockTimestamp - 60)).accounts({
+        vaultAuthority: heidi.publicKey,
+        vault: vaultHeidiPDA,
+      }).signers([heidi]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "UnlockTimestampNotLater", "Should fail with UnlockTimestampNotLater error");
+    }
+    assert.strictEqual(flag, "Failed", "Moving the unlock timestamp backwards should fail");
+  });
+
+  it("Refund sends funds to a named recipient outside the daily limit", async () => {
+    const judy = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, judy.publicKey);
+    const [vaultJudyPDA] = getVaultPDA(judy.publicKey);
+    const refundAmount = 500000;
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: judy.publicKey,
+      vault: vaultJudyPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([judy]).rpc({ commitment: "confirmed" });
+
+    await program.methods.setDailyLimit(new anchor.BN(1000)).accounts({
+      vaultAuthority: judy.publicKey,
+      vault: vaultJudyPDA,
+    }).signers([judy]).rpc({ commitment: "confirmed" });
+
+    await program.methods.deposit(new anchor.BN(refundAmount), null).accounts({
+      user: bob.publicKey,
+      vault: vaultJudyPDA,
+      feeRecipient: null,
+    }).signers([bob]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.refund(new anchor.BN(refundAmount)).accounts({
+        vaultAuthority: bob.publicKey,
+        vault: vaultJudyPDA,
+        recipient: bob.publicKey,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Should fail with ConstraintHasOne error");
+    }
+    assert.strictEqual(flag, "Failed", "Only the vault authority should be able to refund");
+
+    flag = "This should fail";
+    try {
+      await program.methods.refund(new anchor.BN(refundAmount + 1)).accounts({
+        vaultAuthority: judy.publicKey,
+        vault: vaultJudyPDA,
+        recipient: bob.publicKey,
+      }).signers([judy]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "InsufficientBalance", "Should fail with InsufficientBalance error");
+    }
+    assert.strictEqual(flag, "Failed", "Refunds should not dip into the rent-exempt reserve");
+
+    // Judy's own deposit gives the vault room beyond Bob's share, and gives her a deposit record
+    await program.methods.deposit(new anchor.BN(refundAmount), null).accounts({
+      user: judy.publicKey,
+      vault: vaultJudyPDA,
+      feeRecipient: null,
+    }).signers([judy]).rpc({ commitment: "confirmed" });
+
+    flag = "This should fail";
+    try {
+      await program.methods.refund(new anchor.BN(refundAmount + 1)).accounts({
+        vaultAuthority: judy.publicKey,
+        vault: vaultJudyPDA,
+        recipient: bob.publicKey,
+      }).signers([judy]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "RefundExceedsDeposit", "Should fail with RefundExceedsDeposit error");
+    }
+    assert.strictEqual(flag, "Failed", "Refunds should not exceed what the recipient deposited");
+
+    const stranger = anchor.web3.Keypair.generate();
+    flag = "This should fail";
+    try {
+      await program.methods.refund(new anchor.BN(1000)).accounts({
+        vaultAuthority: judy.publicKey,
+        vault: vaultJudyPDA,
+        recipient: stranger.publicKey,
+      }).signers([judy]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      assert.isTrue(error.toString().includes("AccountNotInitialized"), "Should fail with AccountNotInitialized error");
+    }
+    assert.strictEqual(flag, "Failed", "Only recorded depositors should receive refunds");
+
+    flag = "This should fail";
+    try {
+      await program.methods.refund(new anchor.BN(refundAmount)).accounts({
+        vaultAuthority: judy.publicKey,
+        vault: vaultJudyPDA,
+        recipient: judy.publicKey,
+      }).signers([judy]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "RefundToAuthority", "Should fail with RefundToAuthority error");
+    }
+    assert.strictEqual(flag, "Failed", "Refunds should not let the authority bypass withdraw");
+
+    const txSig = await program.methods.refund(new anchor.BN(refundAmount)).accounts({
+      vaultAuthority: judy.publicKey,
+      vault: vaultJudyPDA,
+      recipient: bob.publicKey,
+    }).signers([judy]).rpc({ commitment: "confirmed" });
+
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "refundIssued") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.recipient.toString(), bob.publicKey.toString(), "Event recipient should be Bob");
+        assert.strictEqual(event.data.amount.toString(), refundAmount.toString(), "Event amount should match");
+      }
+    }
+    assert.isTrue(logsEmitted, "RefundIssued should have been emitted");
+  });
+
+  it("Vault label is set at init and editable only by the authority", async () => {
+    const ivan = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, ivan.publicKey);
+    const [vaultIvanPDA] = getVaultPDA(ivan.publicKey);
+
+    await program.methods.initVault(false, "Rainy day fund").accounts({
+      vaultAuthority: ivan.publicKey,
+      vault: vaultIvanPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([ivan]).rpc({ commitment: "confirmed" });
+
+    let vaultData = await program.account.vault.fetch(vaultIvanPDA);
+    assert.strictEqual(vaultData.label, "Rainy day fund", "Label should be set at init");
+
+    let flag = "This should fail";
+    try {
+      await program.methods.setLabel("Hijacked").accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultIvanPDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Should fail with ConstraintHasOne error");
+    }
+    assert.strictEqual(flag, "Failed", "Only the vault authority should be able to set the label");
+
+    flag = "This should fail";
+    try {
+      await program.methods.setLabel("x".repeat(33)).accounts({
+        vaultAuthority: ivan.publicKey,
+        vault: vaultIvanPDA,
+      }).signers([ivan]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "LabelTooLong", "Should fail with LabelTooLong error");
+    }
+    assert.strictEqual(flag, "Failed", "Labels longer than 32 bytes should be rejected");
+
+    flag = "This should fail";
+    try {
+      await program.methods.setLabel("Null\u0000byte").accounts({
+        vaultAuthority: ivan.publicKey,
+        vault: vaultIvanPDA,
+      }).signers([ivan]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "InvalidLabel", "Should fail with InvalidLabel error");
+    }
+    assert.strictEqual(flag, "Failed", "Labels with control characters should be rejected");
+
+    await program.methods.setLabel("College savings").accounts({
+      vaultAuthority: ivan.publicKey,
+      vault: vaultIvanPDA,
+    }).signers([ivan]).rpc({ commitment: "confirmed" });
+
+    vaultData = await program.account.vault.fetch(vaultIvanPDA);
+    assert.strictEqual(vaultData.label, "College savings", "Label should be updated");
+  });
+
+  it("Deposit memo is emitted in the deposit event and validated", async () => {
+    const kate = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, kate.publicKey);
+    const [vaultKatePDA] = getVaultPDA(kate.publicKey);
+    const memo = "INV-2024-0042";
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: kate.publicKey,
+      vault: vaultKatePDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([kate]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.deposit(new anchor.BN(1000000), "x".repeat(65)).accounts({
+        user: bob.publicKey,
+        vault: vaultKatePDA,
+        feeRecipient: null,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "MemoTooLong", "Should fail with MemoTooLong error");
+    }
+    assert.strictEqual(flag, "Failed", "Memos longer than 64 bytes should be rejected");
+
+    flag = "This should fail";
+    try {
+      await program.methods.deposit(new anchor.BN(1000000), "Line\nbreak").accounts({
+        user: bob.publicKey,
+        vault: vaultKatePDA,
+        feeRecipient: null,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "InvalidMemo", "Should fail with InvalidMemo error");
+    }
+    assert.strictEqual(flag, "Failed", "Memos with control characters should be rejected");
+
+    const txSig = await program.methods.deposit(new anchor.BN(1000000), memo).accounts({
+      user: bob.publicKey,
+      vault: vaultKatePDA,
+      feeRecipient: null,
+    }).signers([bob]).rpc({ commitment: "confirmed" });
+
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "depositEvent") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.memo, memo, "Event memo should match");
+        const vaultData = await program.account.vault.fetch(vaultKatePDA);
+        assert.strictEqual(event.data.lastDepositAt.toNumber(), vaultData.lastDepositAt.toNumber(), "Event should carry the last deposit time");
+        assert.isAbove(vaultData.lastDepositAt.toNumber(), 0, "Last deposit time should be recorded");
+      }
+    }
+    assert.isTrue(logsEmitted, "DepositEvent should have been emitted");
+  });
+
+  it("Multi-signer withdrawals need threshold approvals before executing", async () => {
+    const owner = anchor.web3.Keypair.generate();
+    const carol = anchor.web3.Keypair.generate();
+    const dave = anchor.web3.Keypair.generate();
+    const mallory = anchor.web3.Keypair.generate();
+    const recipient = anchor.web3.Keypair.generate();
+    for (const kp of [owner, carol, dave, mallory]) {
+      await airdrop(provider.connection, kp.publicKey);
+    }
+    const [vaultOwnerPDA] = getVaultPDA(owner.publicKey);
+    const getProposalPDA = (id: number) => anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("proposal"), vaultOwnerPDA.toBuffer(), new anchor.BN(id).toArrayLike(Buffer, "le", 8)],
+      program.programId
+    )[0];
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: owner.publicKey,
+      vault: vaultOwnerPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+    await program.methods.deposit(new anchor.BN(5 * anchor.web3.LAMPORTS_PER_SOL), null).accounts({
+      user: owner.publicKey,
+      vault: vaultOwnerPDA,
+      feeRecipient: null,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.setSigners([owner.publicKey, carol.publicKey], 3).accounts({
+        vaultAuthority: owner.publicKey,
+        vault: vaultOwnerPDA,
+      }).signers([owner]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "InvalidThreshold", "Should fail with InvalidThreshold error");
+    }
+    assert.strictEqual(flag, "Failed", "Threshold above the signer count should be rejected");
+
+    await program.methods.setSigners([owner.publicKey, carol.publicKey, dave.publicKey], 2).accounts({
+      vaultAuthority: owner.publicKey,
+      vault: vaultOwnerPDA,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+
+    flag = "This should fail";
+    try {
+      await program.methods.withdraw(new anchor.BN(1000000)).accounts({
+        vaultAuthority: owner.publicKey,
+        vault: vaultOwnerPDA,
+      }).signers([owner]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "MultisigRequired", "Should fail with MultisigRequired error");
+    }
+    assert.strictEqual(flag, "Failed", "Direct withdrawals should be rejected once signers are set");
+
+    const amount = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+    const proposal = getProposalPDA(0);
+
+    flag = "This should fail";
+    try {
+      await program.methods.proposeWithdrawal(new anchor.BN(0), recipient.publicKey).accounts({
+        proposer: owner.publicKey,
+        vault: vaultOwnerPDA,
+        proposal,
+      }).signers([owner]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "ZeroWithdrawal", "Should fail with ZeroWithdrawal error");
+    }
+    assert.strictEqual(flag, "Failed", "Zero-amount proposals should be rejected");
+
+    await program.methods.proposeWithdrawal(amount, recipient.publicKey).accounts({
+      proposer: owner.publicKey,
+      vault: vaultOwnerPDA,
+      proposal,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+
+    flag = "This should fail";
+    try {
+      await program.methods.approveWithdrawal().accounts({
+        approver: owner.publicKey,
+        vault: vaultOwnerPDA,
+        proposal,
+      }).signers([owner]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "AlreadyApproved", "Should fail with AlreadyApproved error");
+    }
+    assert.strictEqual(flag, "Failed", "Duplicate approvals should be rejected");
+
+    flag = "This should fail";
+    try {
+      await program.methods.approveWithdrawal().accounts({
+        approver: mallory.publicKey,
+        vault: vaultOwnerPDA,
+        proposal,
+      }).signers([mallory]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "NotASigner", "Should fail with NotASigner error");
+    }
+    assert.strictEqual(flag, "Failed", "Approvals from non-signers should be rejected");
+
+    flag = "This should fail";
+    try {
+      await program.methods.executeWithdrawal().accounts({
+        executor: owner.publicKey,
+        vault: vaultOwnerPDA,
+        proposal,
+        proposer: owner.publicKey,
+        recipient: recipient.publicKey,
+      }).signers([owner]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "ThresholdNotMet", "Should fail with ThresholdNotMet error");
+    }
+    assert.strictEqual(flag, "Failed", "Execution below the threshold should be rejected");
+
+    await program.methods.approveWithdrawal().accounts({
+      approver: carol.publicKey,
+      vault: vaultOwnerPDA,
+      proposal,
+    }).signers([carol]).rpc({ commitment: "confirmed" });
+
+    await program.methods.executeWithdrawal().accounts({
+      executor: dave.publicKey,
+      vault: vaultOwnerPDA,
+      proposal,
+      proposer: owner.publicKey,
+      recipient: recipient.publicKey,
+    }).signers([dave]).rpc({ commitment: "confirmed" });
+
+    const recipientBalance = await provider.connection.getBalance(recipient.publicKey);
+    assert.strictEqual(recipientBalance, amount.toNumber(), "Recipient should receive the proposed amount");
+    const proposalInfo = await provider.connection.getAccountInfo(proposal);
+    assert.isNull(proposalInfo, "Proposal should be closed after execution");
+    const vaultData = await program.account.vault.fetch(vaultOwnerPDA);
+    assert.strictEqual(vaultData.proposalCount.toNumber(), 1, "Proposal count should advance");
+
+    flag = "This should fail";
+    try {
+      await program.methods.setSigners([], 0).accounts({
+        vaultAuthority: owner.publicKey,
+        vault: vaultOwnerPDA,
+      }).signers([owner]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "ThresholdNotMet", "Should fail with ThresholdNotMet error");
+    }
+    assert.strictEqual(flag, "Failed", "The authority alone should not be able to clear the signers");
+
+    await program.methods.setSigners([owner.publicKey, carol.publicKey], 2).accounts({
+      vaultAuthority: owner.publicKey,
+      vault: vaultOwnerPDA,
+    }).remainingAccounts([
+      { pubkey: dave.publicKey, isWritable: false, isSigner: true },
+    ]).signers([owner, dave]).rpc({ commitment: "confirmed" });
+    const updatedVault = await program.account.vault.fetch(vaultOwnerPDA);
+    assert.strictEqual(updatedVault.signers.length, 2, "Approved signer change should apply");
+  });
+
+  it("Sweep dust moves everything above rent-exemption to the treasury", async () => {
+    const sweeper = anchor.web3.Keypair.generate();
+    const treasury = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, sweeper.publicKey);
+    const [vaultSweeperPDA] = getVaultPDA(sweeper.publicKey);
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: sweeper.publicKey,
+      vault: vaultSweeperPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([sweeper]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.sweepDust().accounts({
+        vaultAuthority: sweeper.publicKey,
+        vault: vaultSweeperPDA,
+        treasury: treasury.publicKey,
+      }).signers([sweeper]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "NothingToSweep", "Should fail with NothingToSweep error");
+    }
+    assert.strictEqual(flag, "Failed", "Sweeping an empty vault should be rejected");
+
+    const deposited = anchor.web3.LAMPORTS_PER_SOL;
+    await program.methods.deposit(new anchor.BN(deposited), null).accounts({
+      user: sweeper.publicKey,
+      vault: vaultSweeperPDA,
+      feeRecipient: null,
+    }).signers([sweeper]).rpc({ commitment: "confirmed" });
+
+    await program.methods.sweepDust().accounts({
+      vaultAuthority: sweeper.publicKey,
+      vault: vaultSweeperPDA,
+      treasury: treasury.publicKey,
+    }).signers([sweeper]).rpc({ commitment: "confirmed" });
+
+    const vaultInfo = await provider.connection.getAccountInfo(vaultSweeperPDA);
+    const rentExemptMinimum = await provider.connection.getMinimumBalanceForRentExemption(vaultInfo.data.length);
+    assert.strictEqual(vaultInfo.lamports, rentExemptMinimum, "Vault should be left at the rent-exempt minimum");
+    assert.strictEqual(await provider.connection.getBalance(treasury.publicKey), deposited, "Treasury should receive the swept lamports");
+    const vaultData = await program.account.vault.fetch(vaultSweeperPDA);
+    assert.strictEqual(vaultData.totalWithdrawn.toNumber(), deposited, "Total withdrawn should include the sweep");
+  });
+
+  it("Deposit fee is split off to the fee recipient", async () => {
+    const operator = anchor.web3.Keypair.generate();
+    const feeRecipient = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, operator.publicKey);
+    const [vaultOperatorPDA] = getVaultPDA(operator.publicKey);
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: operator.publicKey,
+      vault: vaultOperatorPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([operator]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.setDepositFee(1001, feeRecipient.publicKey).accounts({
+        vaultAuthority: operator.publicKey,
+        vault: vaultOperatorPDA,
+      }).signers([operator]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "FeeTooHigh", "Should fail with FeeTooHigh error");
+    }
+    assert.strictEqual(flag, "Failed", "Fees above the cap should be rejected");
+
+    flag = "This should fail";
+    try {
+      await program.methods.setDepositFee(250, anchor.web3.PublicKey.default).accounts({
+        vaultAuthority: operator.publicKey,
+        vault: vaultOperatorPDA,
+      }).signers([operator]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "InvalidFeeRecipient", "Should fail with InvalidFeeRecipient error");
+    }
+    assert.strictEqual(flag, "Failed", "A non-zero fee without a fee recipient should be rejected");
+
+    await program.methods.setDepositFee(250, feeRecipient.publicKey).accounts({
+      vaultAuthority: operator.publicKey,
+      vault: vaultOperatorPDA,
+    }).signers([operator]).rpc({ commitment: "confirmed" });
+
+    const amount = anchor.web3.LAMPORTS_PER_SOL;
+    const expectedFee = amount * 250 / 10000;
+
+    flag = "This should fail";
+    try {
+      await program.methods.deposit(new anchor.BN(amount), null).accounts({
+        user: bob.publicKey,
+        vault: vaultOperatorPDA,
+        feeRecipient: null,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "FeeRecipientMismatch", "Should fail with FeeRecipientMismatch error");
+    }
+    assert.strictEqual(flag, "Failed", "Deposits without the fee recipient should be rejected while a fee is set");
+
+    const txSig = await program.methods.deposit(new anchor.BN(amount), null).accounts({
+      user: bob.publicKey,
+      vault: vaultOperatorPDA,
+      feeRecipient: feeRecipient.publicKey,
+    }).signers([bob]).rpc({ commitment: "confirmed" });
+
+    assert.strictEqual(await provider.connection.getBalance(feeRecipient.publicKey), expectedFee, "Fee recipient should receive the fee");
+    const vaultData = await program.account.vault.fetch(vaultOperatorPDA);
+    assert.strictEqual(vaultData.totalDeposited.toNumber(), amount - expectedFee, "Vault should only count the amount after the fee");
+
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const depositEvent = [...eventParser.parseLogs(tx.meta.logMessages)].find((event) => event.name === "depositEvent");
+    assert.strictEqual(depositEvent.data.fee.toNumber(), expectedFee, "Deposit event should carry the fee");
+  });
+
+  it("Max withdrawable reports the amount every withdrawal guard allows", async () => {
+    const saver = anchor.web3.Keypair.generate();
+    await airdrop(provider.connection, saver.publicKey);
+    const [vaultSaverPDA] = getVaultPDA(saver.publicKey);
+
+    await program.methods.initVault(false, "").accounts({
+      vaultAuthority: saver.publicKey,
+      vault: vaultSaverPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([saver]).rpc({ commitment: "confirmed" });
+
+    await program.methods.deposit(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL), null).accounts({
+      user: saver.publicKey,
+      vault: vaultSaverPDA,
+      feeRecipient: null,
+    }).signers([saver]).rpc({ commitment: "confirmed" });
+
+    const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+    const queryMaxWithdrawable = async () => {
+      const txSig = await program.methods.maxWithdrawable().accounts({
+        vault: vaultSaverPDA,
+      }).rpc({ commitment: "confirmed" });
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const event = [...eventParser.parseLogs(tx.meta.logMessages)].find((event) => event.name === "maxWithdrawable");
+      return event.data.amount.toNumber();
+    };
+
+    assert.strictEqual(await queryMaxWithdrawable(), anchor.web3.LAMPORTS_PER_SOL, "Everything above rent-exemption should be withdrawable");
+
+    const dailyLimit = anchor.web3.LAMPORTS_PER_SOL / 4;
+    await program.methods.setDailyLimit(new anchor.BN(dailyLimit)).accounts({
+      vaultAuthority: saver.publicKey,
+      vault: vaultSaverPDA,
+    }).signers([saver]).rpc({ commitment: "confirmed" });
+    assert.strictEqual(await queryMaxWithdrawable(), dailyLimit, "The daily limit should cap the withdrawable amount");
+
+    await program.methods.setLock(true).accounts({
+      vaultAuthority: saver.publicKey,
+      vault: vaultSaverPDA,
+    }).signers([saver]).rpc({ commitment: "confirmed" });
+    assert.strictEqual(await queryMaxWithdrawable(), 0, "A locked vault should report nothing withdrawable");
+
+    const vaultData = await program.account.vault.fetch(vaultSaverPDA);
+    assert.strictEqual(vaultData.withdrawnToday.toNumber(), 0, "Querying should not touch the daily window");
+  });
 });
 
 async function airdrop(connection: any, address: any, amount = 100 * anchor.web3.LAMPORTS_PER_SOL) {
@@ -16,4 +16,56 @@ pub enum TwitterError {
     MinDislikesReached,
     #[msg("Comment too Long")]
     CommentTooLong,
+    #[msg("New reaction is the same as the current one")]
+    ReactionUnchanged,
+    #[msg("Maximum number of Comments Reached")]
+    MaxCommentsReached,
+    #[msg("Minimum number of Comments Reached")]
+    MinCommentsReached,
+    #[msg("Topic name cannot be empty")]
+    TopicNameEmpty,
+    #[msg("Topic name too long")]
+    TopicNameTooLong,
+    #[msg("Maximum number of Tweets for Topic Reached")]
+    MaxTopicTweetsReached,
+    #[msg("Only the tweet author can perform this action")]
+    NotTweetAuthor,
+    #[msg("No tweet is pinned to this profile")]
+    NoPinnedTweet,
+    #[msg("A tweet cannot quote itself")]
+    CannotQuoteSelf,
+    #[msg("Quoted tweet account is missing or does not match")]
+    QuotedTweetMismatch,
+    #[msg("Maximum number of Quotes Reached")]
+    MaxQuotesReached,
+    #[msg("Cannot follow yourself")]
+    CannotFollowSelf,
+    #[msg("Maximum number of Followers Reached")]
+    MaxFollowersReached,
+    #[msg("Minimum number of Followers Reached")]
+    MinFollowersReached,
+    #[msg("Tip amount must be greater than zero")]
+    TipTooSmall,
+    #[msg("Maximum amount of Tips Reached")]
+    MaxTipsReached,
+    #[msg("Maximum number of Reports Reached")]
+    MaxReportsReached,
+    #[msg("Cannot block yourself")]
+    CannotBlockSelf,
+    #[msg("The tweet author has blocked this user")]
+    UserBlocked,
+    #[msg("Maximum number of Edits Reached")]
+    MaxEditsReached,
+    #[msg("Only the author can interact with a private tweet")]
+    TweetIsPrivate,
+    #[msg("Only followers of the author can interact with this tweet")]
+    FollowersOnly,
+    #[msg("Remove all reactions before deleting the tweet")]
+    TweetHasReactions,
+    #[msg("Maximum number of Tweets Reached")]
+    MaxTweetsReached,
+    #[msg("Wait for the tweet cooldown to pass before tweeting again")]
+    TweetCooldownActive,
+    #[msg("Remove all comments before deleting the tweet")]
+    TweetHasComments,
 }
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{ReportReason, Visibility};
+
+#[event]
+pub struct TweetCreated {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub topic: String,
+    pub visibility: Visibility,
+    pub index: u64,
+}
+
+#[event]
+pub struct TweetEdited {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub updated_at: i64,
+    pub edit_count: u16,
+}
+
+#[event]
+pub struct TweetDeleted {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub topic: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CommentAdded {
+    pub comment: Pubkey,
+    pub tweet: Pubkey,
+    pub comment_author: Pubkey,
+    pub id: u64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct CommentRemoved {
+    pub comment: Pubkey,
+    pub tweet: Pubkey,
+    pub comment_author: Pubkey,
+    pub id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TweetTagged {
+    pub topic: Pubkey,
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub topic_name: String,
+    pub tweet_count: u64,
+}
+
+#[event]
+pub struct TweetPinned {
+    pub profile: Pubkey,
+    pub user: Pubkey,
+    pub tweet: Pubkey,
+}
+
+#[event]
+pub struct TweetUnpinned {
+    pub profile: Pubkey,
+    pub user: Pubkey,
+    pub tweet: Pubkey,
+}
+
+#[event]
+pub struct TweetQuoted {
+    pub tweet: Pubkey,
+    pub quoted_tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub quoted_tweet_author: Pubkey,
+}
+
+#[event]
+pub struct UserFollowed {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub followers_count: u64,
+}
+
+#[event]
+pub struct UserUnfollowed {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub followers_count: u64,
+}
+
+#[event]
+pub struct TweetTipped {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub tipper: Pubkey,
+    pub amount: u64,
+    pub tips_received: u64,
+}
+
+#[event]
+pub struct TweetReported {
+    pub report: Pubkey,
+    pub tweet: Pubkey,
+    pub reporter: Pubkey,
+    pub reason: ReportReason,
+    pub report_count: u64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct UserBlocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+}
+
+#[event]
+pub struct UserUnblocked {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+}
+
+#[event]
+pub struct TweetHidden {
+    pub tweet: Pubkey,
+    pub likes: u64,
+    pub dislikes: u64,
+}
+
+#[event]
+pub struct TweetUnhidden {
+    pub tweet: Pubkey,
+    pub likes: u64,
+    pub dislikes: u64,
+}
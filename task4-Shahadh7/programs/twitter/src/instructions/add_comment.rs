@@ -5,32 +5,54 @@
 /// Requirements:
 /// - Validate that comment content doesn't exceed maximum length
 /// - Initialize a new comment account with proper PDA seeds
-/// - Set comment fields: content, author, parent tweet, and bump
-/// - Use content hash in PDA seeds for unique comment identification
+/// - Set comment fields: id, content, author, parent tweet, timestamp, and bump
+/// - Use the tweet's running comment total in PDA seeds for unique comment identification
+/// - Increment the comment counters on the tweet
+/// - Enforce the tweet visibility, using a follow account as proof of following
+/// - Reject the comment if a block account from the tweet author is passed
 ///
 ///-------------------------------------------------------------------------------
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
 
 use crate::errors::TwitterError;
+use crate::events::CommentAdded;
 use crate::states::*;
 
 pub fn add_comment(ctx: Context<AddCommentContext>, comment_content: String) -> Result<()> {
+    if ctx.accounts.block.is_some() {
+        return Err(TwitterError::UserBlocked.into());
+    }
+
+    ctx.accounts.tweet.check_visibility(&ctx.accounts.comment_author.key(), ctx.accounts.follow.is_some())?;
+
     if comment_content.as_bytes().len() > COMMENT_LENGTH {
         return Err(TwitterError::CommentTooLong.into());
     }
 
+    let tweet = &mut ctx.accounts.tweet;
     let comment = &mut ctx.accounts.comment;
     comment.comment_author = ctx.accounts.comment_author.key();
-    comment.parent_tweet = ctx.accounts.tweet.key();
+    comment.parent_tweet = tweet.key();
+    comment.id = tweet.total_comments;
     comment.content = comment_content;
+    comment.created_at = Clock::get()?.unix_timestamp;
     comment.bump = ctx.bumps.comment;
 
+    tweet.comment_count = tweet.comment_count.checked_add(1).ok_or(TwitterError::MaxCommentsReached)?;
+    tweet.total_comments = tweet.total_comments.checked_add(1).ok_or(TwitterError::MaxCommentsReached)?;
+
+    emit!(CommentAdded {
+        comment: comment.key(),
+        tweet: comment.parent_tweet,
+        comment_author: comment.comment_author,
+        id: comment.id,
+        created_at: comment.created_at,
+    });
+
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(comment_content: String)]
 pub struct AddCommentContext<'info> {
     #[account(mut)]
     pub comment_author: Signer<'info>,
@@ -40,13 +62,24 @@ pub struct AddCommentContext<'info> {
         space = 8 + Comment::INIT_SPACE,
         seeds = [
             COMMENT_SEED.as_bytes(),
-            comment_author.key().as_ref(),
-            {let h = hash(comment_content.as_bytes()); h.to_bytes().as_ref()},
             tweet.key().as_ref(),
+            comment_author.key().as_ref(),
+            tweet.total_comments.to_le_bytes().as_ref(),
         ],
         bump
     )]
     pub comment: Account<'info, Comment>,
+    #[account(mut)]
     pub tweet: Account<'info, Tweet>,
+    #[account(
+        seeds = [BLOCK_SEED.as_bytes(), tweet.tweet_author.as_ref(), comment_author.key().as_ref()],
+        bump = block.bump
+    )]
+    pub block: Option<Account<'info, Block>>,
+    #[account(
+        seeds = [FOLLOW_SEED.as_bytes(), comment_author.key().as_ref(), tweet.tweet_author.as_ref()],
+        bump = follow.bump
+    )]
+    pub follow: Option<Account<'info, Follow>>,
     pub system_program: Program<'info, System>,
 }
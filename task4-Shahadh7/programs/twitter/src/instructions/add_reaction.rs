@@ -6,17 +6,27 @@
 /// - Initialize a new reaction account with proper PDA seeds
 /// - Increment the appropriate counter (likes or dislikes) on the tweet
 /// - Set reaction fields: type, author, parent tweet, and bump
-/// - Handle both Like and Dislike reaction types
+/// - Handle Like, Dislike and SuperLike reaction types
+/// - Add the reaction's weight (Like +1, SuperLike +5, Dislike -1) to the weighted score
+/// - Hide the tweet, emitting TweetHidden, once dislikes outweigh likes past the threshold
+/// - Enforce the tweet visibility, using a follow account as proof of following
+/// - Reject the reaction if a block account from the tweet author is passed
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 
 use crate::errors::TwitterError;
+use crate::events::TweetHidden;
 use crate::states::*;
 
 pub fn add_reaction(ctx: Context<AddReactionContext>, reaction: ReactionType) -> Result<()> {
-    
+    if ctx.accounts.block.is_some() {
+        return Err(TwitterError::UserBlocked.into());
+    }
+
+    ctx.accounts.tweet.check_visibility(&ctx.accounts.reaction_author.key(), ctx.accounts.follow.is_some())?;
+
     let tweet_reaction = &mut ctx.accounts.tweet_reaction;
     tweet_reaction.reaction_author = ctx.accounts.reaction_author.key();
     tweet_reaction.parent_tweet = ctx.accounts.tweet.key();
@@ -24,19 +34,14 @@ pub fn add_reaction(ctx: Context<AddReactionContext>, reaction: ReactionType) ->
     tweet_reaction.bump = ctx.bumps.tweet_reaction;
 
     let tweet = &mut ctx.accounts.tweet;
-    match reaction {
-        ReactionType::Like => {
-            if tweet.likes == u64::MAX {
-                return Err(TwitterError::MaxLikesReached.into());
-            }
-            tweet.likes = tweet.likes.checked_add(1).ok_or(TwitterError::MaxLikesReached)?;
-        }
-        ReactionType::Dislike => {
-            if tweet.dislikes == u64::MAX {
-                return Err(TwitterError::MaxDislikesReached.into());
-            }
-            tweet.dislikes = tweet.dislikes.checked_add(1).ok_or(TwitterError::MaxDislikesReached)?;
-        }
+    tweet.increment_reaction(reaction)?;
+
+    if tweet.update_hidden() == Some(true) {
+        emit!(TweetHidden {
+            tweet: tweet.key(),
+            likes: tweet.likes,
+            dislikes: tweet.dislikes,
+        });
     }
 
     Ok(())
@@ -60,5 +65,15 @@ pub struct AddReactionContext<'info> {
     pub tweet_reaction: Account<'info, Reaction>,
     #[account(mut)]
     pub tweet: Account<'info, Tweet>,
+    #[account(
+        seeds = [BLOCK_SEED.as_bytes(), tweet.tweet_author.as_ref(), reaction_author.key().as_ref()],
+        bump = block.bump
+    )]
+    pub block: Option<Account<'info, Block>>,
+    #[account(
+        seeds = [FOLLOW_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.tweet_author.as_ref()],
+        bump = follow.bump
+    )]
+    pub follow: Option<Account<'info, Follow>>,
     pub system_program: Program<'info, System>,
 }
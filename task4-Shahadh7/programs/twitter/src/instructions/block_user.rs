@@ -0,0 +1,51 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the block user functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Reject attempts to block yourself
+/// - Initialize a new block account with proper PDA seeds (a second block fails as the account exists)
+/// - Set block fields: blocker, blocked, and bump
+/// - Emit a UserBlocked event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::UserBlocked;
+use crate::states::*;
+
+pub fn block_user(ctx: Context<BlockUserContext>) -> Result<()> {
+    let blocker = ctx.accounts.blocker.key();
+    let blocked = ctx.accounts.blocked.key();
+    if blocker == blocked {
+        return Err(TwitterError::CannotBlockSelf.into());
+    }
+
+    let block = &mut ctx.accounts.block;
+    block.blocker = blocker;
+    block.blocked = blocked;
+    block.bump = ctx.bumps.block;
+
+    emit!(UserBlocked { blocker, blocked });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BlockUserContext<'info> {
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+    /// CHECK: Only used as the blocked key in PDA seeds
+    pub blocked: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = blocker,
+        space = 8 + Block::INIT_SPACE,
+        seeds = [BLOCK_SEED.as_bytes(), blocker.key().as_ref(), blocked.key().as_ref()],
+        bump
+    )]
+    pub block: Account<'info, Block>,
+    pub system_program: Program<'info, System>,
+}
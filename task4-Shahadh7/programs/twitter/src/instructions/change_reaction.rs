@@ -0,0 +1,84 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the change reaction functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Verify that the tweet reaction exists and belongs to the reaction author
+/// - Reject a change to the same reaction type
+/// - Decrement the old counter and increment the new one on the tweet
+/// - Swap the old reaction's weight for the new one in the weighted score
+/// - Update the reaction type stored on the tweet reaction account
+/// - Hide or un-hide the tweet when the dislike ratio crosses the threshold
+/// - Enforce the tweet visibility, using a follow account as proof of following
+/// - Reject the change if a block account from the tweet author is passed
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::{TweetHidden, TweetUnhidden};
+use crate::states::*;
+
+pub fn change_reaction(ctx: Context<ChangeReactionContext>, reaction: ReactionType) -> Result<()> {
+    if ctx.accounts.block.is_some() {
+        return Err(TwitterError::UserBlocked.into());
+    }
+
+    ctx.accounts.tweet.check_visibility(&ctx.accounts.reaction_author.key(), ctx.accounts.follow.is_some())?;
+
+    let tweet_reaction = &mut ctx.accounts.tweet_reaction;
+    let tweet = &mut ctx.accounts.tweet;
+
+    if tweet_reaction.reaction == reaction {
+        return Err(TwitterError::ReactionUnchanged.into());
+    }
+
+    tweet.decrement_reaction(&tweet_reaction.reaction)?;
+    tweet.increment_reaction(reaction.clone())?;
+    tweet_reaction.reaction = reaction;
+
+    match tweet.update_hidden() {
+        Some(true) => emit!(TweetHidden {
+            tweet: tweet.key(),
+            likes: tweet.likes,
+            dislikes: tweet.dislikes,
+        }),
+        Some(false) => emit!(TweetUnhidden {
+            tweet: tweet.key(),
+            likes: tweet.likes,
+            dislikes: tweet.dislikes,
+        }),
+        None => {}
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChangeReactionContext<'info> {
+    pub reaction_author: Signer<'info>,
+    #[account(
+        mut,
+        has_one = reaction_author,
+        seeds = [
+            TWEET_REACTION_SEED.as_bytes(),
+            reaction_author.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump = tweet_reaction.bump
+    )]
+    pub tweet_reaction: Account<'info, Reaction>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+    #[account(
+        seeds = [BLOCK_SEED.as_bytes(), tweet.tweet_author.as_ref(), reaction_author.key().as_ref()],
+        bump = block.bump
+    )]
+    pub block: Option<Account<'info, Block>>,
+    #[account(
+        seeds = [FOLLOW_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.tweet_author.as_ref()],
+        bump = follow.bump
+    )]
+    pub follow: Option<Account<'info, Follow>>,
+}
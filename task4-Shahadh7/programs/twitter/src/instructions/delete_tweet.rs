@@ -0,0 +1,57 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the delete tweet functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Only the tweet author can delete the tweet
+/// - Refuse deletion while the tweet still has likes, dislikes or comments
+/// - Close the tweet account and return rent to the tweet author
+/// - Emit a TweetDeleted event
+/// 
+/// NOTE: Reaction and comment PDAs are derived from the tweet address, so closing
+/// a tweet with open reactions or comments would orphan them. Every reaction
+/// (reaction_remove) and comment (comment_remove) has to be removed before the
+/// author can delete the tweet.
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetDeleted;
+use crate::states::*;
+
+pub fn delete_tweet(ctx: Context<DeleteTweetContext>) -> Result<()> {
+    let tweet = &ctx.accounts.tweet;
+
+    if tweet.likes > 0 || tweet.dislikes > 0 {
+        return Err(TwitterError::TweetHasReactions.into());
+    }
+
+    if tweet.comment_count > 0 {
+        return Err(TwitterError::TweetHasComments.into());
+    }
+
+    emit!(TweetDeleted {
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        topic: tweet.topic.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeleteTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+    #[account(
+        mut,
+        has_one = tweet_author,
+        close = tweet_author,
+        seeds = [TWEET_SEED.as_bytes(), tweet_author.key().as_ref(), tweet.index.to_le_bytes().as_ref()],
+        bump = tweet.bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+}
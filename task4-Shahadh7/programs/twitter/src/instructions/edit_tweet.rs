@@ -0,0 +1,52 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the edit tweet functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Only the tweet author can edit the tweet
+/// - Validate that the new content doesn't exceed the maximum length
+/// - Update the content, the updated_at timestamp and the is_edited flag
+/// - Increment the edit counter on the tweet
+/// - Keep likes and dislikes untouched
+/// - Emit a TweetEdited event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetEdited;
+use crate::states::*;
+
+pub fn edit_tweet(ctx: Context<EditTweetContext>, new_content: String) -> Result<()> {
+    if new_content.as_bytes().len() > CONTENT_LENGTH {
+        return Err(TwitterError::ContentTooLong.into());
+    }
+
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.content = new_content;
+    tweet.updated_at = Clock::get()?.unix_timestamp;
+    tweet.is_edited = true;
+    tweet.edit_count = tweet.edit_count.checked_add(1).ok_or(TwitterError::MaxEditsReached)?;
+
+    emit!(TweetEdited {
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        updated_at: tweet.updated_at,
+        edit_count: tweet.edit_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EditTweetContext<'info> {
+    pub tweet_author: Signer<'info>,
+    #[account(
+        mut,
+        has_one = tweet_author,
+        seeds = [TWEET_SEED.as_bytes(), tweet_author.key().as_ref(), tweet.index.to_le_bytes().as_ref()],
+        bump = tweet.bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+}
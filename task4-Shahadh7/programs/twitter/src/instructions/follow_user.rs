@@ -0,0 +1,68 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the follow user functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Reject attempts to follow yourself
+/// - Initialize a new follow account with proper PDA seeds (a second follow fails as the account exists)
+/// - Initialize the followee's profile on first use and increment its followers counter
+/// - Emit a UserFollowed event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::UserFollowed;
+use crate::states::*;
+
+pub fn follow_user(ctx: Context<FollowUserContext>) -> Result<()> {
+    let follower = ctx.accounts.follower.key();
+    let followee = ctx.accounts.followee.key();
+    if follower == followee {
+        return Err(TwitterError::CannotFollowSelf.into());
+    }
+
+    let follow = &mut ctx.accounts.follow;
+    follow.follower = follower;
+    follow.followee = followee;
+    follow.bump = ctx.bumps.follow;
+
+    let followee_profile = &mut ctx.accounts.followee_profile;
+    followee_profile.user = followee;
+    followee_profile.bump = ctx.bumps.followee_profile;
+    followee_profile.followers_count = followee_profile.followers_count.checked_add(1).ok_or(TwitterError::MaxFollowersReached)?;
+
+    emit!(UserFollowed {
+        follower,
+        followee,
+        followers_count: followee_profile.followers_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FollowUserContext<'info> {
+    #[account(mut)]
+    pub follower: Signer<'info>,
+    /// CHECK: Only used as the followed key in PDA seeds
+    pub followee: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = follower,
+        space = 8 + Follow::INIT_SPACE,
+        seeds = [FOLLOW_SEED.as_bytes(), follower.key().as_ref(), followee.key().as_ref()],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+    #[account(
+        init_if_needed,
+        payer = follower,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [PROFILE_SEED.as_bytes(), followee.key().as_ref()],
+        bump
+    )]
+    pub followee_profile: Account<'info, UserProfile>,
+    pub system_program: Program<'info, System>,
+}
@@ -7,19 +7,27 @@
 /// - Initialize a new tweet account with proper PDA seeds
 /// - Set tweet fields: topic, content, author, likes, dislikes, and bump
 /// - Initialize counters (likes and dislikes) to zero
-/// - Use topic in PDA seeds for tweet identification
+/// - Derive the tweet PDA from the author's profile tweet counter, initializing
+///   the profile on first use, and increment the counter on every post
+/// - Reject tweets posted within TWEET_COOLDOWN_SECONDS of the author's previous one
+/// - Store the tweet visibility and emit it in a TweetCreated event
+/// - When quoting, require the quoted tweet account, reject self-quotes and
+///   increment the quote counter on the quoted tweet
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 
 use crate::errors::TwitterError;
+use crate::events::{TweetCreated, TweetQuoted};
 use crate::states::*;
 
 pub fn initialize_tweet(
     ctx: Context<InitializeTweet>,
     topic: String,
     content: String,
+    quoted_tweet: Option<Pubkey>,
+    visibility: Visibility,
 ) -> Result<()> {
     if topic.as_bytes().len() > TOPIC_LENGTH {
         return Err(TwitterError::TopicTooLong.into());
@@ -29,29 +37,90 @@ pub fn initialize_tweet(
         return Err(TwitterError::ContentTooLong.into());
     }
 
+    let now = Clock::get()?.unix_timestamp;
+    let profile = &ctx.accounts.profile;
+    if TWEET_COOLDOWN_SECONDS > 0 && profile.tweet_count > 0 && now - profile.last_tweet_at < TWEET_COOLDOWN_SECONDS {
+        return Err(TwitterError::TweetCooldownActive.into());
+    }
+
     let tweet = &mut ctx.accounts.tweet;
     tweet.tweet_author = ctx.accounts.tweet_authority.key();
     tweet.topic = topic;
     tweet.content = content;
     tweet.likes = 0;
     tweet.dislikes = 0;
+    tweet.updated_at = now;
+    tweet.is_edited = false;
+    tweet.comment_count = 0;
+    tweet.total_comments = 0;
+    tweet.quoted_tweet = quoted_tweet;
+    tweet.quote_count = 0;
+    tweet.tips_received = 0;
+    tweet.report_count = 0;
+    tweet.edit_count = 0;
+    tweet.visibility = visibility;
+    tweet.hidden = false;
+    tweet.weighted_score = 0;
     tweet.bump = ctx.bumps.tweet;
 
+    let profile = &mut ctx.accounts.profile;
+    profile.user = tweet.tweet_author;
+    profile.bump = ctx.bumps.profile;
+    profile.last_tweet_at = now;
+    tweet.index = profile.tweet_count;
+    profile.tweet_count = profile.tweet_count.checked_add(1).ok_or(TwitterError::MaxTweetsReached)?;
+
+    emit!(TweetCreated {
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        topic: tweet.topic.clone(),
+        visibility: tweet.visibility.clone(),
+        index: tweet.index,
+    });
+
+    if let Some(quoted_key) = quoted_tweet {
+        if quoted_key == tweet.key() {
+            return Err(TwitterError::CannotQuoteSelf.into());
+        }
+
+        let quoted = match ctx.accounts.quoted_tweet.as_mut() {
+            Some(quoted) if quoted.key() == quoted_key => quoted,
+            _ => return Err(TwitterError::QuotedTweetMismatch.into()),
+        };
+        quoted.quote_count = quoted.quote_count.checked_add(1).ok_or(TwitterError::MaxQuotesReached)?;
+
+        emit!(TweetQuoted {
+            tweet: tweet.key(),
+            quoted_tweet: quoted_key,
+            tweet_author: tweet.tweet_author,
+            quoted_tweet_author: quoted.tweet_author,
+        });
+    }
+
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(topic: String)]
 pub struct InitializeTweet<'info> {
     #[account(mut)]
     pub tweet_authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [PROFILE_SEED.as_bytes(), tweet_authority.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
     #[account(
         init,
         payer = tweet_authority,
         space = 8 + Tweet::INIT_SPACE,
-        seeds = [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()],
+        seeds = [TWEET_SEED.as_bytes(), tweet_authority.key().as_ref(), profile.tweet_count.to_le_bytes().as_ref()],
         bump
     )]
     pub tweet: Account<'info, Tweet>,
+    #[account(mut)]
+    pub quoted_tweet: Option<Account<'info, Tweet>>,
     pub system_program: Program<'info, System>,
 }
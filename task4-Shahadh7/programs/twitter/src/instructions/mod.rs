@@ -1,14 +1,50 @@
 pub use initialize_tweet::*;
 pub mod initialize_tweet;
 
+pub use edit_tweet::*;
+pub mod edit_tweet;
+
+pub use delete_tweet::*;
+pub mod delete_tweet;
+
 pub use add_reaction::*;
 pub mod add_reaction;
 
 pub use remove_reaction::*;
 pub mod remove_reaction;
 
+pub use change_reaction::*;
+pub mod change_reaction;
+
 pub use add_comment::*;
 pub mod add_comment;
 
 pub use remove_comment::*;
 pub mod remove_comment;
+
+pub use tag_tweet::*;
+pub mod tag_tweet;
+
+pub use pin_tweet::*;
+pub mod pin_tweet;
+
+pub use unpin_tweet::*;
+pub mod unpin_tweet;
+
+pub use follow_user::*;
+pub mod follow_user;
+
+pub use unfollow_user::*;
+pub mod unfollow_user;
+
+pub use tip_tweet::*;
+pub mod tip_tweet;
+
+pub use report_tweet::*;
+pub mod report_tweet;
+
+pub use block_user::*;
+pub mod block_user;
+
+pub use unblock_user::*;
+pub mod unblock_user;
@@ -0,0 +1,50 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the pin tweet functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Only the tweet author can pin the tweet to their profile
+/// - Initialize the user profile account on first use with proper PDA seeds
+/// - Store the pinned tweet on the profile, replacing any previously pinned tweet
+/// - Leave the tweet itself untouched
+/// - Emit a TweetPinned event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetPinned;
+use crate::states::*;
+
+pub fn pin_tweet(ctx: Context<PinTweetContext>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    profile.user = ctx.accounts.user.key();
+    profile.pinned_tweet = Some(ctx.accounts.tweet.key());
+    profile.bump = ctx.bumps.profile;
+
+    emit!(TweetPinned {
+        profile: profile.key(),
+        user: profile.user,
+        tweet: ctx.accounts.tweet.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PinTweetContext<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserProfile::INIT_SPACE,
+        seeds = [PROFILE_SEED.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(constraint = tweet.tweet_author == user.key() @ TwitterError::NotTweetAuthor)]
+    pub tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
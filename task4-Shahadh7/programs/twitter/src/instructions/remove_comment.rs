@@ -3,19 +3,32 @@
 /// TASK: Implement the remove comment functionality for the Twitter program
 /// 
 /// Requirements:
+/// - Verify that the comment exists and belongs to the comment author
+/// - Decrement the comment counter on the parent tweet
 /// - Close the comment account and return rent to comment author
 /// 
-/// NOTE: No implementation logic is needed in the function body - this 
-/// functionality is achieved entirely through account constraints!
-/// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
 
+use crate::errors::TwitterError;
+use crate::events::CommentRemoved;
 use crate::states::*;
 
-pub fn remove_comment(_ctx: Context<RemoveCommentContext>) -> Result<()> {
+pub fn remove_comment(ctx: Context<RemoveCommentContext>) -> Result<()> {
+    let comment = &ctx.accounts.comment;
+    let tweet = &mut ctx.accounts.tweet;
+
+    tweet.comment_count = tweet.comment_count.checked_sub(1).ok_or(TwitterError::MinCommentsReached)?;
+
+    emit!(CommentRemoved {
+        comment: comment.key(),
+        tweet: tweet.key(),
+        comment_author: comment.comment_author,
+        id: comment.id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -26,7 +39,16 @@ pub struct RemoveCommentContext<'info> {
     #[account(
         mut,
         has_one = comment_author,
-        close = comment_author
+        close = comment_author,
+        seeds = [
+            COMMENT_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            comment_author.key().as_ref(),
+            comment.id.to_le_bytes().as_ref(),
+        ],
+        bump = comment.bump
     )]
     pub comment: Account<'info, Comment>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
 }
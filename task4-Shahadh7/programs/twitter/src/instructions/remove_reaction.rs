@@ -5,32 +5,36 @@
 /// Requirements:
 /// - Verify that the tweet reaction exists and belongs to the reaction author
 /// - Decrement the appropriate counter (likes or dislikes) on the tweet
+/// - Take the reaction's weight back out of the tweet's weighted score
+/// - Un-hide the tweet, emitting TweetUnhidden, once the dislike ratio recovers
 /// - Close the tweet reaction account and return rent to reaction author
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 
-use crate::errors::TwitterError;
+use crate::events::{TweetHidden, TweetUnhidden};
 use crate::states::*;
 
 pub fn remove_reaction(ctx: Context<RemoveReactionContext>) -> Result<()> {
     let tweet_reaction = &ctx.accounts.tweet_reaction;
     let tweet = &mut ctx.accounts.tweet;
 
-    match tweet_reaction.reaction {
-        ReactionType::Like => {
-            if tweet.likes == 0 {
-                return Err(TwitterError::MinLikesReached.into());
-            }
-            tweet.likes = tweet.likes.checked_sub(1).ok_or(TwitterError::MinLikesReached)?;
-        }
-        ReactionType::Dislike => {
-            if tweet.dislikes == 0 {
-                return Err(TwitterError::MinDislikesReached.into());
-            }
-            tweet.dislikes = tweet.dislikes.checked_sub(1).ok_or(TwitterError::MinDislikesReached)?;
-        }
+    tweet.decrement_reaction(&tweet_reaction.reaction)?;
+
+    // Removing a like can also tip the ratio towards hiding
+    match tweet.update_hidden() {
+        Some(true) => emit!(TweetHidden {
+            tweet: tweet.key(),
+            likes: tweet.likes,
+            dislikes: tweet.dislikes,
+        }),
+        Some(false) => emit!(TweetUnhidden {
+            tweet: tweet.key(),
+            likes: tweet.likes,
+            dislikes: tweet.dislikes,
+        }),
+        None => {}
     }
 
     Ok(())
@@ -0,0 +1,58 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the report tweet functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Initialize a new report account with proper PDA seeds (a second report
+///   from the same reporter fails as the account exists)
+/// - Set report fields: reporter, tweet, reason, timestamp, and bump
+/// - Increment the report counter on the tweet
+/// - Emit a TweetReported event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetReported;
+use crate::states::*;
+
+pub fn report_tweet(ctx: Context<ReportTweetContext>, reason: ReportReason) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    let report = &mut ctx.accounts.report;
+    report.reporter = ctx.accounts.reporter.key();
+    report.tweet = tweet.key();
+    report.reason = reason.clone();
+    report.created_at = Clock::get()?.unix_timestamp;
+    report.bump = ctx.bumps.report;
+
+    tweet.report_count = tweet.report_count.checked_add(1).ok_or(TwitterError::MaxReportsReached)?;
+
+    emit!(TweetReported {
+        report: report.key(),
+        tweet: report.tweet,
+        reporter: report.reporter,
+        reason,
+        report_count: tweet.report_count,
+        created_at: report.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportTweetContext<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + Report::INIT_SPACE,
+        seeds = [REPORT_SEED.as_bytes(), reporter.key().as_ref(), tweet.key().as_ref()],
+        bump
+    )]
+    pub report: Account<'info, Report>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
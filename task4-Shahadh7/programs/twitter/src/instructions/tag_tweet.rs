@@ -0,0 +1,78 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the tag tweet functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Only the tweet author can tag the tweet with a topic
+/// - Validate that the topic name is not empty and doesn't exceed the maximum length
+/// - Initialize the topic account on first use with proper PDA seeds
+/// - Initialize a tweet-topic account per pair (tagging the same tweet twice fails as the account exists)
+/// - Increment the tweet counter on the topic
+/// - Emit a TweetTagged event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetTagged;
+use crate::states::*;
+
+pub fn tag_tweet(ctx: Context<TagTweetContext>, topic_name: String) -> Result<()> {
+    if topic_name.is_empty() {
+        return Err(TwitterError::TopicNameEmpty.into());
+    }
+
+    if topic_name.as_bytes().len() > TOPIC_NAME_LENGTH {
+        return Err(TwitterError::TopicNameTooLong.into());
+    }
+
+    let topic = &mut ctx.accounts.topic;
+    if topic.name.is_empty() {
+        topic.name = topic_name;
+        topic.bump = ctx.bumps.topic;
+    }
+
+    let tweet_topic = &mut ctx.accounts.tweet_topic;
+    tweet_topic.topic = topic.key();
+    tweet_topic.tweet = ctx.accounts.tweet.key();
+    tweet_topic.bump = ctx.bumps.tweet_topic;
+
+    topic.tweet_count = topic.tweet_count.checked_add(1).ok_or(TwitterError::MaxTopicTweetsReached)?;
+
+    emit!(TweetTagged {
+        topic: topic.key(),
+        tweet: ctx.accounts.tweet.key(),
+        tweet_author: ctx.accounts.tweet_author.key(),
+        topic_name: topic.name.clone(),
+        tweet_count: topic.tweet_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(topic_name: String)]
+pub struct TagTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+    #[account(has_one = tweet_author)]
+    pub tweet: Account<'info, Tweet>,
+    #[account(
+        init_if_needed,
+        payer = tweet_author,
+        space = 8 + Topic::INIT_SPACE,
+        seeds = [TOPIC_SEED.as_bytes(), topic_name.as_bytes()],
+        bump
+    )]
+    pub topic: Account<'info, Topic>,
+    #[account(
+        init,
+        payer = tweet_author,
+        space = 8 + TweetTopic::INIT_SPACE,
+        seeds = [TWEET_TOPIC_SEED.as_bytes(), topic.key().as_ref(), tweet.key().as_ref()],
+        bump
+    )]
+    pub tweet_topic: Account<'info, TweetTopic>,
+    pub system_program: Program<'info, System>,
+}
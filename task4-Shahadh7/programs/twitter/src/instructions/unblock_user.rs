@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the unblock user functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Verify that the block account exists and belongs to the blocker
+/// - Close the block account and return rent to the blocker
+/// - Emit a UserUnblocked event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::events::UserUnblocked;
+use crate::states::*;
+
+pub fn unblock_user(ctx: Context<UnblockUserContext>) -> Result<()> {
+    emit!(UserUnblocked {
+        blocker: ctx.accounts.blocker.key(),
+        blocked: ctx.accounts.blocked.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnblockUserContext<'info> {
+    #[account(mut)]
+    pub blocker: Signer<'info>,
+    /// CHECK: Only used as the blocked key in PDA seeds
+    pub blocked: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = blocker,
+        has_one = blocked,
+        close = blocker,
+        seeds = [BLOCK_SEED.as_bytes(), blocker.key().as_ref(), blocked.key().as_ref()],
+        bump = block.bump
+    )]
+    pub block: Account<'info, Block>,
+}
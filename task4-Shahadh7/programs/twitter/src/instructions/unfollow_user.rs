@@ -0,0 +1,53 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the unfollow user functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Verify that the follow account exists and belongs to the follower
+/// - Decrement the followers counter on the followee's profile
+/// - Close the follow account and return rent to the follower
+/// - Emit a UserUnfollowed event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::UserUnfollowed;
+use crate::states::*;
+
+pub fn unfollow_user(ctx: Context<UnfollowUserContext>) -> Result<()> {
+    let followee_profile = &mut ctx.accounts.followee_profile;
+    followee_profile.followers_count = followee_profile.followers_count.checked_sub(1).ok_or(TwitterError::MinFollowersReached)?;
+
+    emit!(UserUnfollowed {
+        follower: ctx.accounts.follower.key(),
+        followee: ctx.accounts.followee.key(),
+        followers_count: followee_profile.followers_count,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfollowUserContext<'info> {
+    #[account(mut)]
+    pub follower: Signer<'info>,
+    /// CHECK: Only used as the followed key in PDA seeds
+    pub followee: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = follower,
+        has_one = followee,
+        close = follower,
+        seeds = [FOLLOW_SEED.as_bytes(), follower.key().as_ref(), followee.key().as_ref()],
+        bump = follow.bump
+    )]
+    pub follow: Account<'info, Follow>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_bytes(), followee.key().as_ref()],
+        bump = followee_profile.bump
+    )]
+    pub followee_profile: Account<'info, UserProfile>,
+}
@@ -0,0 +1,42 @@
+//-------------------------------------------------------------------------------
+///
+/// TASK: Implement the unpin tweet functionality for the Twitter program
+/// 
+/// Requirements:
+/// - Verify that the user profile exists and belongs to the user
+/// - Fail if no tweet is currently pinned
+/// - Clear the pinned tweet on the profile
+/// - Emit a TweetUnpinned event
+/// 
+///-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetUnpinned;
+use crate::states::*;
+
+pub fn unpin_tweet(ctx: Context<UnpinTweetContext>) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let tweet = profile.pinned_tweet.take().ok_or(TwitterError::NoPinnedTweet)?;
+
+    emit!(TweetUnpinned {
+        profile: profile.key(),
+        user: profile.user,
+        tweet,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnpinTweetContext<'info> {
+    pub user: Signer<'info>,
+    #[account(
+        mut,
+        has_one = user,
+        seeds = [PROFILE_SEED.as_bytes(), user.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+}
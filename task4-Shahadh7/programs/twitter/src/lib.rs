@@ -5,10 +5,21 @@
 /// TWITTER PROGRAM TASK
 /// 
 /// This is a decentralized Twitter-like program built on Solana that allows users to:
-/// - Create tweets with topics and content
-/// - Add reactions (likes/dislikes) to tweets
+/// - Create tweets with topics and content, optionally quoting another tweet
+/// - Make tweets public, followers-only or private
+/// - Edit the content of their own tweets
+/// - Delete their own tweets once all reactions are removed
+/// - Add reactions (likes/dislikes/super likes) to tweets
 /// - Comment on tweets
 /// - Remove their own reactions and comments
+/// - Switch an existing reaction between like and dislike
+/// - Tag tweets with topics for discovery
+/// - Pin one of their own tweets to their profile
+/// - Follow and unfollow other users
+/// - Tip tweet authors with SOL
+/// - Report tweets for moderation
+/// - Block users from reacting to and commenting on their tweets
+/// - Automatically hide heavily disliked tweets
 /// 
 /// INSTRUCTIONS:
 /// Complete the implementation of all instructions by filling in the TODOs.
@@ -23,9 +34,14 @@
 /// - Imports
 /// 
 /// SEEDS:
-/// - Tweet: [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()]
+/// - Tweet: [TWEET_SEED.as_bytes(), tweet_authority.key().as_ref(), profile.tweet_count.to_le_bytes().as_ref()]
 /// - TweetReaction: [TWEET_REACTION_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.key().as_ref()]
-/// - Comment: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_tweet.key().as_ref()]
+/// - Comment: [COMMENT_SEED.as_bytes(), tweet.key().as_ref(), comment_author.key().as_ref(), tweet.total_comments.to_le_bytes().as_ref()]
+/// - Topic: [TOPIC_SEED.as_bytes(), topic_name.as_bytes()]
+/// - UserProfile: [PROFILE_SEED.as_bytes(), user.key().as_ref()]
+/// - Follow: [FOLLOW_SEED.as_bytes(), follower.key().as_ref(), followee.key().as_ref()]
+/// - Report: [REPORT_SEED.as_bytes(), reporter.key().as_ref(), tweet.key().as_ref()]
+/// - Block: [BLOCK_SEED.as_bytes(), blocker.key().as_ref(), blocked.key().as_ref()]
 /// 
 /// GOOD LUCK!
 /// 
@@ -35,6 +51,7 @@ use crate::instructions::*;
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod states;
 
@@ -45,8 +62,14 @@ pub mod twitter {
 
     use super::*;
 
-    pub fn initialize(ctx: Context<InitializeTweet>, topic: String, content: String) -> Result<()> {
-        initialize_tweet(ctx, topic, content)
+    pub fn initialize(ctx: Context<InitializeTweet>, topic: String, content: String, quoted_tweet: Option<Pubkey>, visibility: states::Visibility) -> Result<()> {
+        initialize_tweet(ctx, topic, content, quoted_tweet, visibility)
+    }
+    pub fn edit_tweet(ctx: Context<EditTweetContext>, new_content: String) -> Result<()> {
+        instructions::edit_tweet(ctx, new_content)
+    }
+    pub fn delete_tweet(ctx: Context<DeleteTweetContext>) -> Result<()> {
+        instructions::delete_tweet(ctx)
     }
     pub fn like_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
         add_reaction(ctx, states::ReactionType::Like)
@@ -54,13 +77,46 @@ pub mod twitter {
     pub fn dislike_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
         add_reaction(ctx, states::ReactionType::Dislike)
     }
+    pub fn super_like_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
+        add_reaction(ctx, states::ReactionType::SuperLike)
+    }
     pub fn reaction_remove(ctx: Context<RemoveReactionContext>) -> Result<()> {
         remove_reaction(ctx)
     }
+    pub fn change_reaction(ctx: Context<ChangeReactionContext>, reaction: states::ReactionType) -> Result<()> {
+        instructions::change_reaction(ctx, reaction)
+    }
     pub fn comment_tweet(ctx: Context<AddCommentContext>, comment_content: String) -> Result<()> {
         add_comment(ctx, comment_content)
     }
     pub fn comment_remove(ctx: Context<RemoveCommentContext>) -> Result<()> {
         remove_comment(ctx)
     }
+    pub fn tag_tweet(ctx: Context<TagTweetContext>, topic_name: String) -> Result<()> {
+        instructions::tag_tweet(ctx, topic_name)
+    }
+    pub fn pin_tweet(ctx: Context<PinTweetContext>) -> Result<()> {
+        instructions::pin_tweet(ctx)
+    }
+    pub fn unpin_tweet(ctx: Context<UnpinTweetContext>) -> Result<()> {
+        instructions::unpin_tweet(ctx)
+    }
+    pub fn follow_user(ctx: Context<FollowUserContext>) -> Result<()> {
+        instructions::follow_user(ctx)
+    }
+    pub fn unfollow_user(ctx: Context<UnfollowUserContext>) -> Result<()> {
+        instructions::unfollow_user(ctx)
+    }
+    pub fn tip_tweet(ctx: Context<TipTweetContext>, amount: u64) -> Result<()> {
+        instructions::tip_tweet(ctx, amount)
+    }
+    pub fn report_tweet(ctx: Context<ReportTweetContext>, reason: states::ReportReason) -> Result<()> {
+        instructions::report_tweet(ctx, reason)
+    }
+    pub fn block_user(ctx: Context<BlockUserContext>) -> Result<()> {
+        instructions::block_user(ctx)
+    }
+    pub fn unblock_user(ctx: Context<UnblockUserContext>) -> Result<()> {
+        instructions::unblock_user(ctx)
+    }
 }
@@ -1,17 +1,60 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::TwitterError;
+
 pub const TOPIC_LENGTH: usize = 32;
 pub const CONTENT_LENGTH: usize = 500;
 pub const COMMENT_LENGTH: usize = 500;
+pub const TOPIC_NAME_LENGTH: usize = 32;
+
+// When true, like/dislike counters stop at u64::MAX instead of failing the reaction
+pub const SATURATE_REACTIONS: bool = true;
+// Tweets with more dislikes than likes are hidden once dislikes reach this count
+pub const HIDE_DISLIKE_THRESHOLD: u64 = 10;
+// Minimum seconds between two tweets by the same author; zero disables the check
+pub const TWEET_COOLDOWN_SECONDS: i64 = 0;
 
 pub const TWEET_SEED: &str = "TWEET_SEED";
 pub const TWEET_REACTION_SEED: &str = "TWEET_REACTION_SEED";
 pub const COMMENT_SEED: &str = "COMMENT_SEED";
+pub const TOPIC_SEED: &str = "topic";
+pub const PROFILE_SEED: &str = "profile";
+pub const FOLLOW_SEED: &str = "follow";
+pub const REPORT_SEED: &str = "report";
+pub const BLOCK_SEED: &str = "block";
+pub const TWEET_TOPIC_SEED: &str = "tweet_topic";
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
 pub enum ReactionType {
     Like,
     Dislike,
+    SuperLike, // counted as a like in the raw counters, but weighs more in weighted_score
+}
+
+impl ReactionType {
+    // Contribution of a single reaction to Tweet::weighted_score
+    pub fn weight(&self) -> i64 {
+        match self {
+            ReactionType::Like => 1,
+            ReactionType::Dislike => -1,
+            ReactionType::SuperLike => 5,
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum ReportReason {
+    Spam,
+    Abuse,
+    Misinformation,
+    Other,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, InitSpace)]
+pub enum Visibility {
+    Public,
+    Followers,
+    Private,
 }
 
 #[account]
@@ -24,9 +67,76 @@ pub struct Tweet {
     pub content: String,
     pub likes: u64,
     pub dislikes: u64,
+    pub updated_at: i64,
+    pub is_edited: bool,
+    pub comment_count: u64,
+    pub total_comments: u64,
+    pub quoted_tweet: Option<Pubkey>,
+    pub quote_count: u64,
+    pub tips_received: u64, // total lamports tipped to the author
+    pub report_count: u64,
+    pub edit_count: u16,
+    pub visibility: Visibility,
+    pub hidden: bool,
+    pub weighted_score: i64, // sum of ReactionType::weight over the live reactions
+    pub index: u64, // position among the author's tweets, part of the PDA seeds
     pub bump: u8,
 }
 
+impl Tweet {
+    pub fn increment_reaction(&mut self, kind: ReactionType) -> Result<()> {
+        let weight = kind.weight();
+        let (counter, error) = match kind {
+            ReactionType::Like | ReactionType::SuperLike => (&mut self.likes, TwitterError::MaxLikesReached),
+            ReactionType::Dislike => (&mut self.dislikes, TwitterError::MaxDislikesReached),
+        };
+        *counter = if SATURATE_REACTIONS {
+            counter.saturating_add(1)
+        } else {
+            counter.checked_add(1).ok_or(error)?
+        };
+        self.weighted_score = self.weighted_score.saturating_add(weight);
+        Ok(())
+    }
+
+    pub fn decrement_reaction(&mut self, kind: &ReactionType) -> Result<()> {
+        match kind {
+            ReactionType::Like | ReactionType::SuperLike => {
+                self.likes = self.likes.checked_sub(1).ok_or(TwitterError::MinLikesReached)?;
+            }
+            ReactionType::Dislike => {
+                self.dislikes = self.dislikes.checked_sub(1).ok_or(TwitterError::MinDislikesReached)?;
+            }
+        }
+        // The score is signed, so saturate instead of flooring at zero; it can never wrap around
+        self.weighted_score = self.weighted_score.saturating_sub(kind.weight());
+        Ok(())
+    }
+
+    // Re-evaluates the dislike ratio; returns the new hidden state only when it flips
+    pub fn update_hidden(&mut self) -> Option<bool> {
+        let hidden = self.dislikes > self.likes && self.dislikes >= HIDE_DISLIKE_THRESHOLD;
+        if hidden == self.hidden {
+            return None;
+        }
+        self.hidden = hidden;
+        Some(hidden)
+    }
+
+    // The author can always interact with their own tweet
+    pub fn check_visibility(&self, actor: &Pubkey, is_follower: bool) -> Result<()> {
+        if *actor == self.tweet_author {
+            return Ok(());
+        }
+        match self.visibility {
+            Visibility::Public => Ok(()),
+            Visibility::Followers if is_follower => Ok(()),
+            Visibility::Followers => Err(TwitterError::FollowersOnly.into()),
+            Visibility::Private => Err(TwitterError::TweetIsPrivate.into()),
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Reaction {
@@ -41,7 +151,63 @@ pub struct Reaction {
 pub struct Comment {
     pub comment_author: Pubkey,
     pub parent_tweet: Pubkey,
+    pub id: u64,
     #[max_len(COMMENT_LENGTH)]
     pub content: String,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Topic {
+    #[max_len(TOPIC_NAME_LENGTH)]
+    pub name: String,
+    pub tweet_count: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserProfile {
+    pub user: Pubkey,
+    pub pinned_tweet: Option<Pubkey>,
+    pub followers_count: u64,
+    pub tweet_count: u64, // next tweet index; tweets by this user live at indices 0..tweet_count
+    pub last_tweet_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Follow {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Report {
+    pub reporter: Pubkey,
+    pub tweet: Pubkey,
+    pub reason: ReportReason,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Block {
+    pub blocker: Pubkey,
+    pub blocked: Pubkey,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TweetTopic {
+    pub topic: Pubkey,
+    pub tweet: Pubkey,
     pub bump: u8,
 }
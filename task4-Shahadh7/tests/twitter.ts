@@ -3,12 +3,17 @@ import { Program } from "@coral-xyz/anchor";
 import { Twitter } from "../target/types/twitter";
 import { PublicKey } from '@solana/web3.js';
 import { assert } from "chai";
-import crypto from "crypto";
 
 
 const TWEET_SEED = "TWEET_SEED";
 const TWEET_REACTION = "TWEET_REACTION_SEED";
 const COMMENT_SEED = "COMMENT_SEED";
+const TOPIC_SEED = "topic";
+const PROFILE_SEED = "profile";
+const FOLLOW_SEED = "follow";
+const REPORT_SEED = "report";
+const BLOCK_SEED = "block";
+const TWEET_TOPIC_SEED = "tweet_topic";
 
 describe("twitter", () => {
   const provider = anchor.AnchorProvider.env();
@@ -51,12 +56,13 @@ describe("twitter", () => {
   describe("Initialize Tweet", async () => {
     it("Should successfully initialize a tweet with valid topic and content", async () => {
       await airdrop(provider.connection, bob.publicKey);
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_bob1, bob.publicKey);
 
-      await program.methods.initialize(topic_bob1, content_bob1).accounts(
+      await program.methods.initialize(topic_bob1, content_bob1, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -67,12 +73,13 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with exactly 32-byte topic (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edge_case, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_edge_case, bob.publicKey);
 
-      await program.methods.initialize(topic_edge_case, content_bob1).accounts(
+      await program.methods.initialize(topic_edge_case, content_bob1, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -83,12 +90,13 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with exactly 500-byte content (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress("EdgeContent", bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, "EdgeContent", bob.publicKey);
 
-      await program.methods.initialize("EdgeContent", content_edge_case).accounts(
+      await program.methods.initialize("EdgeContent", content_edge_case, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -99,12 +107,13 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with empty content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress("EmptyContent", bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, "EmptyContent", bob.publicKey);
 
-      await program.methods.initialize("EmptyContent", empty_content).accounts(
+      await program.methods.initialize("EmptyContent", empty_content, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -115,12 +124,13 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with single character topic and content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, single_char_topic, bob.publicKey);
 
-      await program.methods.initialize(single_char_topic, single_char_content).accounts(
+      await program.methods.initialize(single_char_topic, single_char_content, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -131,12 +141,13 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with unicode characters and emojis", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(unicode_topic, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, unicode_topic, bob.publicKey);
 
-      await program.methods.initialize(unicode_topic, unicode_content).accounts(
+      await program.methods.initialize(unicode_topic, unicode_content, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -150,17 +161,19 @@ describe("twitter", () => {
 
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob2, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_bob2, bob.publicKey);
 
-        await program.methods.initialize(topic_bob2, content_bob2).accounts(
+        await program.methods.initialize(topic_bob2, content_bob2, null, { public: {} }).accounts(
           {
             tweetAuthority: bob.publicKey,
             tweet: tweet_pkey,
+            quotedTweet: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
       } catch (error) {
-        assert.strictEqual(error.message, "Max seed length exceeded", "Expected 'Max seed length exceeded' error for topic longer than 32 bytes");
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TopicTooLong", "Expected 'TopicTooLong' error for topic longer than 32 bytes");
         should_fail = "Failed"
       }
       assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with topic longer than 32 bytes")
@@ -169,12 +182,13 @@ describe("twitter", () => {
     it("Should fail to initialize tweet when content exceeds 500 bytes", async () => {
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob3, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_bob3, bob.publicKey);
 
-        await program.methods.initialize(topic_bob3, content_bob3).accounts(
+        await program.methods.initialize(topic_bob3, content_bob3, null, { public: {} }).accounts(
           {
             tweetAuthority: bob.publicKey,
             tweet: tweet_pkey,
+            quotedTweet: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -186,32 +200,35 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with content longer than 500 bytes")
     });
 
-    it("Should fail to initialize duplicate tweet with same topic and author", async () => {
+    it("Should fail to initialize a tweet at an index the author already used", async () => {
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
 
-        await program.methods.initialize(topic_bob1, "Different content").accounts(
+        await program.methods.initialize("Reused Index", "Different content", null, { public: {} }).accounts(
           {
             tweetAuthority: bob.publicKey,
             tweet: tweet_pkey,
+            quotedTweet: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
       } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintSeeds", "Expected 'ConstraintSeeds' error for a tweet address that is not at the author's next index");
         should_fail = "Failed"
-        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error for duplicate tweet with same topic and author")
       }
-      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed when trying to create duplicate tweet with same topic and author")
+      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed when reusing an index the author already posted at")
     });
 
     it("Should successfully initialize second tweet with different topic for same author", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_bob4, bob.publicKey);
 
-      await program.methods.initialize(topic_bob4, content_bob4).accounts(
+      await program.methods.initialize(topic_bob4, content_bob4, null, { public: {} }).accounts(
         {
           tweetAuthority: bob.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -222,12 +239,13 @@ describe("twitter", () => {
 
     it("Should allow different users to create tweets with same topic", async () => {
       await airdrop(provider.connection, charlie.publicKey);
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_bob1, charlie.publicKey);
 
-      await program.methods.initialize(topic_bob1, "Charlie's version").accounts(
+      await program.methods.initialize(topic_bob1, "Charlie's version", null, { public: {} }).accounts(
         {
           tweetAuthority: charlie.publicKey,
           tweet: tweet_pkey,
+          quotedTweet: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -236,13 +254,34 @@ describe("twitter", () => {
         program, tweet_pkey, charlie.publicKey, topic_bob1, "Charlie's version", 0, 0, tweet_bump
       )
     });
+
+    it("Should let clients enumerate an author's tweets by index", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.isAbove(profileData.lastTweetAt.toNumber(), 0, "Posting should record the time of the latest tweet");
+
+      const topics = [];
+      for (let index = 0; index < profileData.tweetCount.toNumber(); index++) {
+        const [tweet_pkey, tweet_bump] = getTweetAddress(bob.publicKey, index, program.programId);
+        const tweetData = await program.account.tweet.fetch(tweet_pkey);
+        assert.strictEqual(tweetData.index.toNumber(), index, "Tweet should store the index it was posted at");
+        assert.strictEqual(tweetData.tweetAuthor.toString(), bob.publicKey.toString(), "Tweet should belong to Bob");
+        topics.push(tweetData.topic);
+      }
+
+      assert.deepEqual(
+        topics,
+        [topic_bob1, topic_edge_case, "EdgeContent", "EmptyContent", single_char_topic, unicode_topic, topic_bob4],
+        "Bob's tweets should be listed in posting order, skipping failed attempts"
+      );
+    });
   });
 
   describe("Add Reaction", async () => {
     it("Should successfully add like reaction to tweet", async () => {
       await airdrop(provider.connection, alice.publicKey);
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -250,6 +289,8 @@ describe("twitter", () => {
           reactionAuthor: alice.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -262,7 +303,7 @@ describe("twitter", () => {
     });
 
     it("Should correctly set reaction type to 'like' enum variant", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let reactionData = await program.account.reaction.fetch(reaction_pkey);
@@ -271,7 +312,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to like the same tweet twice", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -281,6 +322,8 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -296,7 +339,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to dislike a tweet that is already liked", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -306,6 +349,8 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -321,7 +366,7 @@ describe("twitter", () => {
     });
 
     it("Should successfully add dislike reaction to different tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.dislikeTweet().accounts(
@@ -329,6 +374,8 @@ describe("twitter", () => {
           reactionAuthor: alice.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -342,7 +389,7 @@ describe("twitter", () => {
     });
 
     it("Should correctly set reaction type to 'dislike' enum variant", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let reactionData = await program.account.reaction.fetch(reaction_pkey);
@@ -351,7 +398,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to dislike the same tweet twice", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -361,6 +408,8 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -380,6 +429,8 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -394,7 +445,7 @@ describe("twitter", () => {
     });
 
     it("Should allow multiple users to react to the same tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -402,6 +453,8 @@ describe("twitter", () => {
           reactionAuthor: charlie.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -416,7 +469,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to react to non-existent tweet", async () => {
-      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("NonExistent", bob.publicKey, program.programId);
+      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddressByTopic("NonExistent", bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, fake_tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -426,6 +479,8 @@ describe("twitter", () => {
             reactionAuthor: alice.publicKey,
             tweetReaction: reaction_pkey,
             tweet: fake_tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -439,7 +494,7 @@ describe("twitter", () => {
 
   describe("Remove Reaction", async () => {
     it("Should successfully remove existing reaction from tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.reactionRemove().accounts(
@@ -456,7 +511,7 @@ describe("twitter", () => {
     });
 
     it("Should properly delete reaction account after removal", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail"
@@ -471,7 +526,7 @@ describe("twitter", () => {
 
     it("Should allow adding new reaction after previous reaction was removed", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -479,6 +534,8 @@ describe("twitter", () => {
           reactionAuthor: alice.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -492,7 +549,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove non-existent reaction", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, charlie.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -512,7 +569,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove another user's reaction", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -536,8 +593,8 @@ describe("twitter", () => {
   describe("Add Comment", async () => {
     it("Should fail when attempting to add comment exceeding length limit", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice1, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
       let should_fail = "This Should Fail"
       try {
@@ -546,6 +603,8 @@ describe("twitter", () => {
             commentAuthor: alice.publicKey,
             comment: comment_pkey,
             tweet: tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -559,14 +618,16 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with valid length to tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
       await program.methods.commentTweet(comment_alice2).accounts(
         {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -576,15 +637,17 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with exactly 500 characters (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const max_comment = "C".repeat(500);
-      const [comment_pkey, comment_bump] = getCommentAddress(max_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
       await program.methods.commentTweet(max_comment).accounts(
         {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -595,15 +658,17 @@ describe("twitter", () => {
     });
 
     it("Should successfully add empty comment to tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const empty_comment = "";
-      const [comment_pkey, comment_bump] = getCommentAddress(empty_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
       await program.methods.commentTweet(empty_comment).accounts(
         {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -614,15 +679,17 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with unicode characters and emojis", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
       await program.methods.commentTweet(unicode_comment).accounts(
         {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -633,15 +700,17 @@ describe("twitter", () => {
     });
 
     it("Should allow multiple users to comment on the same tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const charlie_comment = "Charlie's comment here";
-      const [comment_pkey, comment_bump] = getCommentAddress(charlie_comment, charlie.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, charlie.publicKey);
 
       await program.methods.commentTweet(charlie_comment).accounts(
         {
           commentAuthor: charlie.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -652,9 +721,9 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to comment on non-existent tweet", async () => {
-      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("FakeTweet", bob.publicKey, program.programId);
+      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddressByTopic("FakeTweet", bob.publicKey, program.programId);
       const test_comment = "This should fail";
-      const [comment_pkey, comment_bump] = getCommentAddress(test_comment, alice.publicKey, fake_tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(fake_tweet_pkey, alice.publicKey, 0, program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -663,6 +732,8 @@ describe("twitter", () => {
             commentAuthor: alice.publicKey,
             comment: comment_pkey,
             tweet: fake_tweet_pkey,
+            block: null,
+            follow: null,
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -673,40 +744,46 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Should not be able to comment on a non-existent tweet");
     });
 
-    it("Should fail when attempting to create duplicate comment with same content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+    it("Should allow duplicate comment with same content under a new id", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
-      let should_fail = "This should fail";
-      try {
-        await program.methods.commentTweet(comment_alice2).accounts(
-          {
-            commentAuthor: alice.publicKey,
-            comment: comment_pkey,
-            tweet: tweet_pkey,
-            systemProgram: anchor.web3.SystemProgram.programId
-          }
-        ).signers([alice]).rpc({ commitment: "confirmed" })
-      } catch (error) {
-        should_fail = "Failed"
-        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when trying to create duplicate comment")
-      }
-      assert.strictEqual(should_fail, "Failed", "Should not be able to create duplicate comment with same content from same user");
+      await program.methods.commentTweet(comment_alice2).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, comment_pkey, alice.publicKey, tweet_pkey, comment_alice2, comment_bump
+      )
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "2", "Tweet comment count should include both comments");
     });
   });
 
   describe("Remove Comment", async () => {
     it("Should successfully remove existing comment from tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, alice.publicKey, 0, program.programId);
 
       await program.methods.commentRemove().accounts(
         {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
+          tweet: tweet_pkey,
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
 
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "1", "Tweet comment count should be decremented after removal");
+      assert.strictEqual(tweetData.totalComments.toString(), "2", "Tweet comment total should not be decremented after removal");
+
       let thisShouldFail = "This should fail"
       try {
         let commentData = await program.account.comment.fetch(comment_pkey);
@@ -719,9 +796,8 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove non-existent comment", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
-      const fake_comment = "This comment doesn't exist";
-      const [comment_pkey, comment_bump] = getCommentAddress(fake_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, alice.publicKey, 99, program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -739,9 +815,8 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove another user's comment", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
-      const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, alice.publicKey, 2, program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -758,9 +833,9 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Should not be able to remove someone else's comment (authorization check)");
     });
 
-    it("Should allow recreating comment with same content after deletion", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+    it("Should allow commenting with same content after deletion", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, alice.publicKey);
 
       // Recreate the same comment that was deleted
       await program.methods.commentTweet(comment_alice2).accounts(
@@ -768,6 +843,8 @@ describe("twitter", () => {
           commentAuthor: alice.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([alice]).rpc({ commitment: "confirmed" })
@@ -780,7 +857,7 @@ describe("twitter", () => {
 
   describe("Edge Cases and Error Handling", async () => {
     it("Should allow tweet author to react to their own tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
 
       await program.methods.dislikeTweet().accounts(
@@ -788,6 +865,8 @@ describe("twitter", () => {
           reactionAuthor: bob.publicKey,
           tweetReaction: reaction_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -799,15 +878,17 @@ describe("twitter", () => {
     });
 
     it("Should allow tweet author to comment on their own tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       const bob_comment = "Thanks for the likes everyone!";
-      const [comment_pkey, comment_bump] = getCommentAddress(bob_comment, bob.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, bob.publicKey);
 
       await program.methods.commentTweet(bob_comment).accounts(
         {
           commentAuthor: bob.publicKey,
           comment: comment_pkey,
           tweet: tweet_pkey,
+          block: null,
+          follow: null,
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -819,46 +900,1147 @@ describe("twitter", () => {
 
     it("Should maintain correct final state across all tweets and reactions", async () => {
       // Bob's first tweet should have: 2 likes, 1 dislike
-      const [tweet1_pkey, tweet1_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet1_pkey, tweet1_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
       await checkTweet(program, tweet1_pkey, bob.publicKey, topic_bob1, content_bob1, 2, 1, tweet1_bump);
 
       // Bob's second tweet should have: 1 like, 0 dislikes
-      const [tweet2_pkey, tweet2_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet2_pkey, tweet2_bump] = getTweetAddressByTopic(topic_bob4, bob.publicKey, program.programId);
       await checkTweet(program, tweet2_pkey, bob.publicKey, topic_bob4, content_bob4, 1, 0, tweet2_bump);
 
       // Charlie's tweet should have: 0 likes, 0 dislikes
-      const [tweet3_pkey, tweet3_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet3_pkey, tweet3_bump] = getTweetAddressByTopic(topic_bob1, charlie.publicKey, program.programId);
       await checkTweet(program, tweet3_pkey, charlie.publicKey, topic_bob1, "Charlie's version", 0, 0, tweet3_bump);
     });
   });
 
-});
+  describe("Edit Tweet", async () => {
+    const edited_content_bob1 = "This is my first tweet on this app, edited!";
 
+    it("Should fail when non-author attempts to edit tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
 
-async function airdrop(connection: any, address: any, amount = 1000000000) {
-  await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
-}
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.editTweet(edited_content_bob1).accounts(
+          {
+            tweetAuthor: alice.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Only the tweet author should be able to edit the tweet")
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_bob1, content_bob1, 2, 1, tweet_bump);
+    });
 
-function getCommentAddress(comment_content: string, author: PublicKey, parent_tweet: PublicKey, programID: PublicKey) {
-  let hexString = crypto.createHash('sha256').update(comment_content, 'utf-8').digest('hex');
-  let content_seed = Uint8Array.from(Buffer.from(hexString, 'hex'));
+    it("Should fail when attempting to edit tweet with content exceeding length limit", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
 
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.editTweet(content_bob3).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ContentTooLong", "Expected 'ContentTooLong' error for content longer than 500 bytes");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet edit should have failed with content longer than 500 bytes")
+    });
 
-  return PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode(COMMENT_SEED),
-      author.toBuffer(),
-      content_seed,
-      parent_tweet.toBuffer(),
-    ], programID);
-}
+    it("Should successfully edit tweet content and keep reactions", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const before = await program.account.tweet.fetch(tweet_pkey);
+      assert.isFalse(before.isEdited, "Tweet should not be marked as edited before editing");
+      assert.strictEqual(before.editCount, 0, "Tweet edit count should start at 0");
 
-function getTweetAddress(topic: string, author: PublicKey, programID: PublicKey) {
-  return PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode(topic),
-      anchor.utils.bytes.utf8.encode(TWEET_SEED),
-      author.toBuffer()
+      await program.methods.editTweet(edited_content_bob1).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_bob1, edited_content_bob1, 2, 1, tweet_bump);
+      const after = await program.account.tweet.fetch(tweet_pkey);
+      assert.isTrue(after.isEdited, "Tweet should be marked as edited");
+      assert.strictEqual(after.editCount, 1, "Tweet edit count should be 1 after one edit");
+      assert.isTrue(after.updatedAt.gte(before.updatedAt), "Tweet updated_at should not move backwards");
+    });
+  });
+
+  describe("Change Reaction", async () => {
+    it("Should fail when changing reaction to the same type", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.changeReaction({ dislike: {} }).accounts(
+          {
+            reactionAuthor: bob.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            block: null,
+            follow: null,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ReactionUnchanged", "Expected 'ReactionUnchanged' error when reaction type does not change");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Changing a reaction to the same type should fail")
+    });
+
+    it("Should successfully change dislike to like", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.changeReaction({ like: {} }).accounts(
+        {
+          reactionAuthor: bob.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: null,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_bob1, undefined, 3, 0, tweet_bump);
+      await checkReaction(program, reaction_pkey, bob.publicKey, tweet_pkey, reaction_bump);
+      const reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { like: {} }, "Reaction type should be updated to like");
+    });
+  });
+
+  describe("Tag Tweet", async () => {
+    const topic_name = "solana";
+
+    it("Should fail when attempting to tag tweet with an empty topic name", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [topic_pkey, topic_bump] = getTopicAddress(empty_topic, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.tagTweet(empty_topic).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            topic: topic_pkey,
+            tweetTopic: getTweetTopicAddress(topic_pkey, tweet_pkey, program.programId)[0],
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TopicNameEmpty", "Expected 'TopicNameEmpty' error for an empty topic name");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tagging with an empty topic name should fail")
+    });
+
+    it("Should fail when non-author attempts to tag tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [topic_pkey, topic_bump] = getTopicAddress(topic_name, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.tagTweet(topic_name).accounts(
+          {
+            tweetAuthor: alice.publicKey,
+            tweet: tweet_pkey,
+            topic: topic_pkey,
+            tweetTopic: getTweetTopicAddress(topic_pkey, tweet_pkey, program.programId)[0],
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Only the tweet author should be able to tag the tweet")
+    });
+
+    it("Should successfully tag tweets and count them on the topic", async () => {
+      const [tweet_bob_pkey, tweet_bob_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_charlie_pkey, tweet_charlie_bump] = getTweetAddressByTopic(topic_bob1, charlie.publicKey, program.programId);
+      const [topic_pkey, topic_bump] = getTopicAddress(topic_name, program.programId);
+
+      await program.methods.tagTweet(topic_name).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_bob_pkey,
+          topic: topic_pkey,
+          tweetTopic: getTweetTopicAddress(topic_pkey, tweet_bob_pkey, program.programId)[0],
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await program.methods.tagTweet(topic_name).accounts(
+        {
+          tweetAuthor: charlie.publicKey,
+          tweet: tweet_charlie_pkey,
+          topic: topic_pkey,
+          tweetTopic: getTweetTopicAddress(topic_pkey, tweet_charlie_pkey, program.programId)[0],
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      const topicData = await program.account.topic.fetch(topic_pkey);
+      assert.strictEqual(topicData.name, topic_name, `Topic name should be "${topic_name}" but was "${topicData.name}"`);
+      assert.strictEqual(topicData.tweetCount.toString(), "2", "Topic should count both tagged tweets");
+      assert.strictEqual(topicData.bump, topic_bump, "Topic bump should match");
+    });
+
+    it("Should fail when tagging the same tweet with the same topic twice", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [topic_pkey, topic_bump] = getTopicAddress(topic_name, program.programId);
+      const [tweet_topic_pkey, tweet_topic_bump] = getTweetTopicAddress(topic_pkey, tweet_pkey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.tagTweet(topic_name).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            topic: topic_pkey,
+            tweetTopic: tweet_topic_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when tagging the same tweet twice")
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tag the same tweet with a topic twice");
+
+      const topicData = await program.account.topic.fetch(topic_pkey);
+      assert.strictEqual(topicData.tweetCount.toString(), "2", "A duplicate tag should not be counted");
+    });
+  });
+
+  describe("Pin Tweet", async () => {
+    it("Should fail when attempting to pin another user's tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.pinTweet().accounts(
+          {
+            user: alice.publicKey,
+            profile: profile_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotTweetAuthor", "Expected 'NotTweetAuthor' error when pinning another user's tweet");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Pinning another user's tweet should fail")
+    });
+
+    it("Should successfully pin own tweet without touching its counters", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      const before = await program.account.tweet.fetch(tweet_pkey);
+
+      await program.methods.pinTweet().accounts(
+        {
+          user: bob.publicKey,
+          profile: profile_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.strictEqual(profileData.user.toString(), bob.publicKey.toString(), "Profile user should be Bob");
+      assert.strictEqual(profileData.pinnedTweet.toString(), tweet_pkey.toString(), "Pinned tweet should be Bob's tweet");
+      assert.strictEqual(profileData.bump, profile_bump, "Profile bump should match");
+
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_bob1, before.content, before.likes.toNumber(), before.dislikes.toNumber(), tweet_bump);
+    });
+
+    it("Should successfully unpin tweet and fail to unpin twice", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+
+      await program.methods.unpinTweet().accounts(
+        {
+          user: bob.publicKey,
+          profile: profile_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.isNull(profileData.pinnedTweet, "Pinned tweet should be cleared");
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.unpinTweet().accounts(
+          {
+            user: bob.publicKey,
+            profile: profile_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NoPinnedTweet", "Expected 'NoPinnedTweet' error when nothing is pinned");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Unpinning without a pinned tweet should fail")
+    });
+  });
+
+  describe("Quote Tweet", async () => {
+    const topic_quote = "Quoting Bob";
+
+    it("Should fail when the quoted tweet account is not passed", async () => {
+      const [bob_tweet_pkey, bob_tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_quote, alice.publicKey);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.initialize(topic_quote, "Look at this", bob_tweet_pkey, { public: {} }).accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            tweet: tweet_pkey,
+            quotedTweet: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "QuotedTweetMismatch", "Expected 'QuotedTweetMismatch' error when the quoted tweet is missing");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Quoting without passing the quoted tweet should fail")
+    });
+
+    it("Should successfully quote a tweet and increment its quote count", async () => {
+      const [bob_tweet_pkey, bob_tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_quote, alice.publicKey);
+      const before = await program.account.tweet.fetch(bob_tweet_pkey);
+
+      await program.methods.initialize(topic_quote, "Look at this", bob_tweet_pkey, { public: {} }).accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          tweet: tweet_pkey,
+          quotedTweet: bob_tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.quotedTweet.toString(), bob_tweet_pkey.toString(), "Quoted tweet should be Bob's tweet");
+      const after = await program.account.tweet.fetch(bob_tweet_pkey);
+      assert.strictEqual(after.quoteCount.toNumber(), before.quoteCount.toNumber() + 1, "Quote count should be incremented");
+    });
+  });
+
+  describe("Follow User", async () => {
+    it("Should fail when attempting to follow yourself", async () => {
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, alice.publicKey, program.programId);
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.followUser().accounts(
+          {
+            follower: alice.publicKey,
+            followee: alice.publicKey,
+            follow: follow_pkey,
+            followeeProfile: profile_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotFollowSelf", "Expected 'CannotFollowSelf' error when following yourself");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Following yourself should fail")
+    });
+
+    it("Should successfully follow a user and reject a second follow", async () => {
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, bob.publicKey, program.programId);
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+
+      await program.methods.followUser().accounts(
+        {
+          follower: alice.publicKey,
+          followee: bob.publicKey,
+          follow: follow_pkey,
+          followeeProfile: profile_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.strictEqual(profileData.followersCount.toNumber(), 1, "Bob should have one follower");
+      const followData = await program.account.follow.fetch(follow_pkey);
+      assert.strictEqual(followData.follower.toString(), alice.publicKey.toString(), "Follower should be Alice");
+      assert.strictEqual(followData.followee.toString(), bob.publicKey.toString(), "Followee should be Bob");
+      assert.strictEqual(followData.bump, follow_bump, "Follow bump should match");
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.followUser().accounts(
+          {
+            follower: alice.publicKey,
+            followee: bob.publicKey,
+            follow: follow_pkey,
+            followeeProfile: profile_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when following the same user twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Following the same user twice should fail")
+    });
+
+    it("Should successfully unfollow a user", async () => {
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, bob.publicKey, program.programId);
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+
+      await program.methods.unfollowUser().accounts(
+        {
+          follower: alice.publicKey,
+          followee: bob.publicKey,
+          follow: follow_pkey,
+          followeeProfile: profile_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const profileData = await program.account.userProfile.fetch(profile_pkey);
+      assert.strictEqual(profileData.followersCount.toNumber(), 0, "Bob should have no followers");
+      const followInfo = await provider.connection.getAccountInfo(follow_pkey);
+      assert.isNull(followInfo, "Follow account should be closed");
+    });
+  });
+
+  describe("Tip Tweet", async () => {
+    const tip_amount = 10000;
+
+    it("Should fail when attempting to tip zero lamports", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.tipTweet(new anchor.BN(0)).accounts(
+          {
+            tipper: alice.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: bob.publicKey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TipTooSmall", "Expected 'TipTooSmall' error for a zero tip");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tipping zero lamports should fail")
+    });
+
+    it("Should fail when the author account does not match the tweet author", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.tipTweet(new anchor.BN(tip_amount)).accounts(
+          {
+            tipper: alice.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: charlie.publicKey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Expected 'ConstraintHasOne' error for a wrong author account");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Tipping the wrong author should fail")
+    });
+
+    it("Should successfully tip the tweet author", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const authorBalanceBefore = await provider.connection.getBalance(bob.publicKey);
+
+      await program.methods.tipTweet(new anchor.BN(tip_amount)).accounts(
+        {
+          tipper: alice.publicKey,
+          tweet: tweet_pkey,
+          tweetAuthor: bob.publicKey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const authorBalanceAfter = await provider.connection.getBalance(bob.publicKey);
+      assert.strictEqual(authorBalanceAfter - authorBalanceBefore, tip_amount, "Tweet author should receive the tip");
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.tipsReceived.toNumber(), tip_amount, "Tweet should record the tip");
+    });
+  });
+
+  describe("Report Tweet", async () => {
+    it("Should successfully report a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [report_pkey, report_bump] = getReportAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reportTweet({ spam: {} }).accounts(
+        {
+          reporter: alice.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const reportData = await program.account.report.fetch(report_pkey);
+      assert.strictEqual(reportData.reporter.toString(), alice.publicKey.toString(), "Reporter should be alice");
+      assert.strictEqual(reportData.tweet.toString(), tweet_pkey.toString(), "Report should point to the tweet");
+      assert.deepEqual(reportData.reason, { spam: {} }, "Report reason should be spam");
+      assert.strictEqual(reportData.bump, report_bump, "Report bump should match");
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toString(), "1", "Tweet report count should be 1");
+    });
+
+    it("Should fail when reporting the same tweet twice", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.reportTweet({ abuse: {} }).accounts(
+          {
+            reporter: alice.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), error.logs)
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Reporting the same tweet twice should fail")
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toString(), "1", "Tweet report count should still be 1");
+    });
+  });
+
+  describe("Block User", async () => {
+    const mallory = anchor.web3.Keypair.generate();
+    const topic_block = "Before The Block";
+
+    before(async () => {
+      await airdrop(provider.connection, mallory.publicKey);
+
+      // Mallory reacts before being blocked, so there is a reaction left to change afterwards
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_block, bob.publicKey);
+      await program.methods.initialize(topic_block, "Reactions welcome, for now", null, { public: {} }).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          quotedTweet: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const [reaction_pkey, reaction_bump] = getReactionAddress(mallory.publicKey, tweet_pkey, program.programId);
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: mallory.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([mallory]).rpc({ commitment: "confirmed" })
+    });
+
+    it("Should fail when attempting to block yourself", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.blockUser().accounts(
+          {
+            blocker: bob.publicKey,
+            blocked: bob.publicKey,
+            block: block_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotBlockSelf", "Expected 'CannotBlockSelf' error when blocking yourself");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocking yourself should fail")
+    });
+
+    it("Should successfully block a user", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, mallory.publicKey, program.programId);
+
+      await program.methods.blockUser().accounts(
+        {
+          blocker: bob.publicKey,
+          blocked: mallory.publicKey,
+          block: block_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const blockData = await program.account.block.fetch(block_pkey);
+      assert.strictEqual(blockData.blocker.toString(), bob.publicKey.toString(), "Blocker should be bob");
+      assert.strictEqual(blockData.blocked.toString(), mallory.publicKey.toString(), "Blocked user should be mallory");
+      assert.strictEqual(blockData.bump, block_bump, "Block bump should match");
+    });
+
+    it("Should reject a reaction from a blocked user", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(mallory.publicKey, tweet_pkey, program.programId);
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, mallory.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.likeTweet().accounts(
+          {
+            reactionAuthor: mallory.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            block: block_pkey,
+            follow: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([mallory]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "UserBlocked", "Expected 'UserBlocked' error for a blocked reaction");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked user should not be able to react")
+    });
+
+    it("Should reject a reaction change from a blocked user", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_block, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(mallory.publicKey, tweet_pkey, program.programId);
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, mallory.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.changeReaction({ dislike: {} }).accounts(
+          {
+            reactionAuthor: mallory.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            block: block_pkey,
+            follow: null,
+          }
+        ).signers([mallory]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "UserBlocked", "Expected 'UserBlocked' error for a blocked reaction change");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked user should not be able to change a reaction")
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_block, undefined, 1, 0, tweet_bump);
+    });
+
+    it("Should reject a comment from a blocked user", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_bob1, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, mallory.publicKey);
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, mallory.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.commentTweet(comment_alice2).accounts(
+          {
+            commentAuthor: mallory.publicKey,
+            comment: comment_pkey,
+            tweet: tweet_pkey,
+            block: block_pkey,
+            follow: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([mallory]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "UserBlocked", "Expected 'UserBlocked' error for a blocked comment");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Blocked user should not be able to comment")
+    });
+
+    it("Should successfully unblock a user", async () => {
+      const [block_pkey, block_bump] = getBlockAddress(bob.publicKey, mallory.publicKey, program.programId);
+
+      await program.methods.unblockUser().accounts(
+        {
+          blocker: bob.publicKey,
+          blocked: mallory.publicKey,
+          block: block_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const blockInfo = await provider.connection.getAccountInfo(block_pkey);
+      assert.isNull(blockInfo, "Block account should be closed");
+    });
+  });
+
+  describe("Tweet Visibility", async () => {
+    const nina = anchor.web3.Keypair.generate();
+    const topic_private = "PrivateTweet";
+    const topic_followers = "FollowersTweet";
+
+    before(async () => {
+      await airdrop(provider.connection, nina.publicKey);
+      for (const [topic, visibility] of [[topic_private, { private: {} }], [topic_followers, { followers: {} }]] as const) {
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic, bob.publicKey);
+        await program.methods.initialize(topic, "Not for everyone", null, visibility as any).accounts(
+          {
+            tweetAuthority: bob.publicKey,
+            tweet: tweet_pkey,
+            quotedTweet: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      }
+    });
+
+    it("Should store the visibility set at creation", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_private, bob.publicKey, program.programId);
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.deepEqual(tweetData.visibility, { private: {} }, "Tweet should be private");
+    });
+
+    it("Should reject a reaction on a private tweet from anyone but the author", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_private, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(nina.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.likeTweet().accounts(
+          {
+            reactionAuthor: nina.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            block: null,
+            follow: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([nina]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetIsPrivate", "Expected 'TweetIsPrivate' error for a private tweet");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Reacting to a private tweet should fail")
+    });
+
+    it("Should require a follow account to comment on a followers-only tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_followers, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, nina.publicKey);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.commentTweet("Hello there").accounts(
+          {
+            commentAuthor: nina.publicKey,
+            comment: comment_pkey,
+            tweet: tweet_pkey,
+            block: null,
+            follow: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([nina]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "FollowersOnly", "Expected 'FollowersOnly' error for a non-follower");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Non-followers should not be able to comment")
+
+      const [follow_pkey, follow_bump] = getFollowAddress(nina.publicKey, bob.publicKey, program.programId);
+      const [profile_pkey, profile_bump] = getProfileAddress(bob.publicKey, program.programId);
+      await program.methods.followUser().accounts(
+        {
+          follower: nina.publicKey,
+          followee: bob.publicKey,
+          follow: follow_pkey,
+          followeeProfile: profile_pkey,
+        }
+      ).signers([nina]).rpc({ commitment: "confirmed" })
+
+      await program.methods.commentTweet("Hello there").accounts(
+        {
+          commentAuthor: nina.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: follow_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([nina]).rpc({ commitment: "confirmed" })
+
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toNumber(), 1, "Follower comment should be counted");
+    });
+  });
+
+  describe("Hide Disliked Tweet", async () => {
+    const topic_hidden = "HiddenTopic";
+    const dislikers = Array.from({ length: 10 }, () => anchor.web3.Keypair.generate());
+
+    before(async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_hidden, bob.publicKey);
+      await program.methods.initialize(topic_hidden, "Unpopular opinion", null, { public: {} }).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          quotedTweet: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+      for (const disliker of dislikers) {
+        await airdrop(provider.connection, disliker.publicKey);
+      }
+    });
+
+    it("Should hide the tweet once dislikes reach the threshold", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_hidden, bob.publicKey, program.programId);
+
+      for (const disliker of dislikers) {
+        let tweetData = await program.account.tweet.fetch(tweet_pkey);
+        assert.isFalse(tweetData.hidden, "Tweet should stay visible below the threshold");
+
+        const [reaction_pkey, reaction_bump] = getReactionAddress(disliker.publicKey, tweet_pkey, program.programId);
+        await program.methods.dislikeTweet().accounts(
+          {
+            reactionAuthor: disliker.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            block: null,
+            follow: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([disliker]).rpc({ commitment: "confirmed" })
+      }
+
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.dislikes.toNumber(), 10, "All dislikes should be counted");
+      assert.isTrue(tweetData.hidden, "Tweet should be hidden at the threshold");
+    });
+
+    it("Should un-hide the tweet when a dislike is removed", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_hidden, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(dislikers[0].publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionRemove().accounts(
+        {
+          reactionAuthor: dislikers[0].publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([dislikers[0]]).rpc({ commitment: "confirmed" })
+
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.dislikes.toNumber(), 9, "Dislike should be removed");
+      assert.isFalse(tweetData.hidden, "Tweet should be visible again below the threshold");
+    });
+  });
+
+  describe("Delete Tweet", async () => {
+    const topic_delete = "DeleteMe";
+    const liker = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await airdrop(provider.connection, liker.publicKey);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_delete, bob.publicKey);
+      await program.methods.initialize(topic_delete, "Short-lived tweet", null, { public: {} }).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          quotedTweet: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const [reaction_pkey, reaction_bump] = getReactionAddress(liker.publicKey, tweet_pkey, program.programId);
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: liker.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([liker]).rpc({ commitment: "confirmed" })
+
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey, liker.publicKey);
+      await program.methods.commentTweet("Gone soon").accounts(
+        {
+          commentAuthor: liker.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([liker]).rpc({ commitment: "confirmed" })
+    });
+
+    it("Should fail to delete a tweet that still has reactions", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_delete, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHasReactions", "Expected 'TweetHasReactions' error while reactions are open");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Deleting a tweet with reactions should fail")
+    });
+
+    it("Should fail when someone other than the author deletes the tweet", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_delete, bob.publicKey, program.programId);
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: liker.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([liker]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Only the author should be able to delete the tweet")
+    });
+
+    it("Should fail to delete a tweet that still has comments", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_delete, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(liker.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionRemove().accounts(
+        {
+          reactionAuthor: liker.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([liker]).rpc({ commitment: "confirmed" })
+
+      let should_fail = "This Should Fail"
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHasComments", "Expected 'TweetHasComments' error while comments are open");
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Deleting a tweet with comments should fail")
+    });
+
+    it("Should delete the tweet once all reactions and comments are removed", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_delete, bob.publicKey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, liker.publicKey, 0, program.programId);
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: liker.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([liker]).rpc({ commitment: "confirmed" })
+
+      await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const tweetInfo = await provider.connection.getAccountInfo(tweet_pkey);
+      assert.isNull(tweetInfo, "Tweet account should be closed");
+    });
+  });
+
+  describe("Weighted Reactions", async () => {
+    const topic_weighted = "WeightedTopic";
+    const fan = anchor.web3.Keypair.generate();
+    const critic = anchor.web3.Keypair.generate();
+
+    before(async () => {
+      await airdrop(provider.connection, fan.publicKey);
+      await airdrop(provider.connection, critic.publicKey);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, topic_weighted, bob.publicKey);
+      await program.methods.initialize(topic_weighted, "Rate me", null, { public: {} }).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweet: tweet_pkey,
+          quotedTweet: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+    });
+
+    it("Should weigh a super like more than a dislike", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_weighted, bob.publicKey, program.programId);
+
+      for (const [reactor, method] of [[fan, "superLikeTweet"], [critic, "dislikeTweet"]] as const) {
+        const [reaction_pkey, reaction_bump] = getReactionAddress(reactor.publicKey, tweet_pkey, program.programId);
+        await program.methods[method]().accounts(
+          {
+            reactionAuthor: reactor.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            block: null,
+            follow: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([reactor]).rpc({ commitment: "confirmed" })
+      }
+
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_weighted, "Rate me", 1, 1);
+      const tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.weightedScore.toNumber(), 4, "Super like (+5) and dislike (-1) should add up to 4");
+    });
+
+    it("Should swap weights when a super like is changed and drop them on removal", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddressByTopic(topic_weighted, bob.publicKey, program.programId);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(fan.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.changeReaction({ dislike: {} }).accounts(
+        {
+          reactionAuthor: fan.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          block: null,
+          follow: null,
+        }
+      ).signers([fan]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.weightedScore.toNumber(), -2, "Two dislikes should score -2");
+      await checkTweet(program, tweet_pkey, bob.publicKey, topic_weighted, "Rate me", 0, 2);
+
+      await program.methods.reactionRemove().accounts(
+        {
+          reactionAuthor: fan.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([fan]).rpc({ commitment: "confirmed" })
+
+      tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.weightedScore.toNumber(), -1, "Removing a dislike should add its weight back");
+    });
+  });
+
+});
+
+
+async function airdrop(connection: any, address: any, amount = 1000000000) {
+  await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
+}
+
+function getCommentAddress(parent_tweet: PublicKey, author: PublicKey, id: number, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(COMMENT_SEED),
+      parent_tweet.toBuffer(),
+      author.toBuffer(),
+      new anchor.BN(id).toArrayLike(Buffer, "le", 8),
+    ], programID);
+}
+
+async function getNextCommentAddress(program: anchor.Program<Twitter>, parent_tweet: PublicKey, author: PublicKey) {
+  const tweetData = await program.account.tweet.fetch(parent_tweet);
+  return getCommentAddress(parent_tweet, author, tweetData.totalComments.toNumber(), program.programId);
+}
+
+function getTweetAddress(author: PublicKey, index: number, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_SEED),
+      author.toBuffer(),
+      new anchor.BN(index).toArrayLike(Buffer, "le", 8),
+    ], programID);
+}
+
+// Tweets are addressed by the author's tweet counter; remember which index each test topic was posted at
+const tweetIndexes = new Map<string, number>();
+
+async function getNextTweetAddress(program: anchor.Program<Twitter>, topic: string, author: PublicKey) {
+  const [profile_pkey, profile_bump] = getProfileAddress(author, program.programId);
+  const profileData = await program.account.userProfile.fetchNullable(profile_pkey);
+  const index = profileData ? profileData.tweetCount.toNumber() : 0;
+  tweetIndexes.set(`${author.toBase58()}:${topic}`, index);
+  return getTweetAddress(author, index, program.programId);
+}
+
+// Topics that were never posted resolve to an index no author will reach
+function getTweetAddressByTopic(topic: string, author: PublicKey, programID: PublicKey) {
+  const index = tweetIndexes.get(`${author.toBase58()}:${topic}`) ?? Number.MAX_SAFE_INTEGER;
+  return getTweetAddress(author, index, programID);
+}
+
+function getTopicAddress(topic_name: string, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TOPIC_SEED),
+      anchor.utils.bytes.utf8.encode(topic_name),
+    ], programID);
+}
+
+function getTweetTopicAddress(topic: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_TOPIC_SEED),
+      topic.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getProfileAddress(user: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(PROFILE_SEED),
+      user.toBuffer(),
+    ], programID);
+}
+
+function getFollowAddress(follower: PublicKey, followee: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(FOLLOW_SEED),
+      follower.toBuffer(),
+      followee.toBuffer(),
+    ], programID);
+}
+
+function getReportAddress(reporter: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(REPORT_SEED),
+      reporter.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getBlockAddress(blocker: PublicKey, blocked: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(BLOCK_SEED),
+      blocker.toBuffer(),
+      blocked.toBuffer(),
     ], programID);
 }
 